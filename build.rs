@@ -0,0 +1,38 @@
+use std::{fs, io, path::Path};
+
+/// Directories whose sources determine the answers, relative to the package root.
+const SOURCE_DIRS: [&str; 2] = ["src", "util/src"];
+
+/// Hashes `bytes` into `hash` using 64-bit FNV-1a, matching the cache's own hashing.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(hash, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Hashes the path and contents of every file in `dir`, recursively and in order of their paths.
+fn hash_dir(hash: u64, dir: &Path) -> io::Result<u64> {
+    let mut paths: Vec<_> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    paths.sort();
+    paths.into_iter().try_fold(hash, |hash, path| {
+        let hash = fnv1a(hash, path.to_string_lossy().as_bytes());
+        if path.is_dir() {
+            hash_dir(hash, &path)
+        } else {
+            Ok(fnv1a(hash, &fs::read(&path)?))
+        }
+    })
+}
+
+/// Sets `AOC_BUILD_ID` to a hash of the sources, which keys the answer cache.
+fn main() -> io::Result<()> {
+    let mut hash = 0xcbf29ce484222325;
+    for dir in SOURCE_DIRS {
+        println!("cargo:rerun-if-changed={dir}");
+        hash = hash_dir(hash, Path::new(dir))?;
+    }
+    println!("cargo:rustc-env=AOC_BUILD_ID={hash:016x}");
+    Ok(())
+}
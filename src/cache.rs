@@ -0,0 +1,80 @@
+use std::{env, fs, io, path::PathBuf};
+
+/// Hash of the sources this binary was built from, set by the build script.
+const BUILD_ID: &str = env!("AOC_BUILD_ID");
+
+/// Environment variable naming the cache directory. The cache is disabled when it's unset.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// A directory of answers from earlier runs.
+///
+/// Each entry is a file named after its key, which consists of the day, the solver variant, a hash of the input contents and
+/// the build id. The build id is a hash of the sources, computed once at build time, so rebuilding with any change to the
+/// code makes older entries unreachable. They stay on disk until [`Cache::clear`] removes them.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+/// Hashes `bytes` using 64-bit FNV-1a, which unlike `DefaultHasher` is guaranteed to be stable between Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl Cache {
+    /// Opens the cache in `dir`, which is created once the first answers are stored.
+    pub fn new(dir: impl Into<PathBuf>) -> Cache {
+        Cache { dir: dir.into() }
+    }
+
+    /// Opens the cache in the directory named by [`CACHE_DIR_VAR`], or returns `None` if it's unset.
+    pub fn from_env() -> Option<Cache> {
+        env::var_os(CACHE_DIR_VAR).map(Cache::new)
+    }
+
+    fn entry_path(&self, day: u8, variant: &str, input: &str) -> PathBuf {
+        self.dir.join(format!(
+            "day{day}-{:016x}-{:016x}-{BUILD_ID}",
+            fnv1a(variant.as_bytes()),
+            fnv1a(input.as_bytes())
+        ))
+    }
+
+    /// Returns the answers to part 1 and part 2 of `input`, if they have been cached for this build.
+    pub fn get(&self, day: u8, variant: &str, input: &str) -> Option<[String; 2]> {
+        let text = fs::read_to_string(self.entry_path(day, variant, input)).ok()?;
+        let (part1, part2) = text.split_once('\n')?;
+        Some([part1.to_owned(), part2.trim_end_matches('\n').to_owned()])
+    }
+
+    /// Stores the answers to part 1 and part 2 of `input`.
+    pub fn insert(&self, day: u8, variant: &str, input: &str, answers: [&str; 2]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(
+            self.entry_path(day, variant, input),
+            format!("{}\n{}\n", answers[0], answers[1]),
+        )
+    }
+
+    /// Removes the entries of `day`, or of every day if `day` is `None`, regardless of which build they belong to. Returns
+    /// the number of removed entries.
+    pub fn clear(&self, day: Option<u8>) -> io::Result<usize> {
+        if !self.dir.is_dir() {
+            return Ok(0);
+        }
+        let prefix = match day {
+            Some(day) => format!("day{day}-"),
+            None => "day".to_owned(),
+        };
+        let mut removed = 0;
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            if entry.file_name().to_string_lossy().starts_with(&prefix) {
+                fs::remove_file(entry.path())?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
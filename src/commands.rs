@@ -0,0 +1,316 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+    str::FromStr,
+};
+
+use crate::{
+    format::{json_string, Format},
+    input::{self, DayInputs},
+    solutions::{
+        day5::Day5,
+        day8::{CommonGoalStep, CycleReport, Day8, NextZStep, NodeSelector},
+        Solver,
+    },
+};
+
+/// Usage lines of every day-specific command, without the process name.
+pub const USAGE: [&str; 6] = [
+    "5 trace [<from> <to>] <value>",
+    "5 seeds <location_start> <location_end>",
+    "8 jump [<from> <to>] <steps>",
+    "8 dot [<from> <to>] [cycles]",
+    "8 analyze [<from> <to>] [--format=<text|json>]",
+    "8 steps <from> <to>",
+];
+
+/// Error returned when a day-specific command can't be run.
+#[derive(Debug)]
+pub enum CommandError {
+    /// The day has no command with this name.
+    UnknownCommand,
+    /// The command exists, but its arguments are missing or invalid.
+    InvalidArguments,
+    /// `input/dayN` is a directory, but commands work on a single input.
+    BatchInput,
+    Io(io::Error),
+    /// The command can't print its output in this format.
+    UnsupportedFormat(Format),
+    /// The command ran, but the input doesn't allow it to finish.
+    Failed(Box<dyn Error>),
+}
+
+impl From<io::Error> for CommandError {
+    fn from(err: io::Error) -> Self {
+        CommandError::Io(err)
+    }
+}
+
+/// Reads the single input of `day` and parses it using the solver `S`.
+fn load_solver<S: Solver>(day: u8) -> Result<S, CommandError> {
+    let DayInputs::Single(input) = input::load(day)? else {
+        return Err(CommandError::BatchInput);
+    };
+    let mut solver = S::new();
+    solver.parse_input(&input);
+    Ok(solver)
+}
+
+fn parse_args<T: FromStr, const N: usize>(args: &[&str]) -> Result<[T; N], CommandError> {
+    let args: [&str; N] = args.try_into().map_err(|_| CommandError::InvalidArguments)?;
+    let parsed: Vec<T> = args
+        .iter()
+        .map(|arg| arg.parse().map_err(|_| CommandError::InvalidArguments))
+        .collect::<Result<_, _>>()?;
+    Ok(parsed.try_into().ok().unwrap())
+}
+
+fn day5_trace(args: &[&str]) -> Result<(), CommandError> {
+    let (from, to, value) = match args {
+        [value] => ("seed", "location", value),
+        [from, to, value] => (*from, *to, value),
+        _ => return Err(CommandError::InvalidArguments),
+    };
+    let [value] = parse_args(&[value])?;
+    let day5: Day5 = load_solver(5)?;
+    let steps = day5.trace(from, to, value).map_err(|err| CommandError::Failed(err.into()))?;
+    for step in steps {
+        match step.rule {
+            Some(rule) => println!("{} {} (by {rule})", step.category, step.value),
+            None => println!("{} {}", step.category, step.value),
+        }
+    }
+    Ok(())
+}
+
+fn day5_seeds(args: &[&str]) -> Result<(), CommandError> {
+    let [start, end] = parse_args(args)?;
+    let day5: Day5 = load_solver(5)?;
+    let seeds = day5
+        .seeds_reaching(start..end)
+        .map_err(|err| CommandError::Failed(err.into()))?;
+    let listed = seeds.intersection(&day5.seed_ranges());
+    println!("Seeds reaching locations {start}..{end}: {:?}", seeds.ranges());
+    println!("Of which listed in the almanac: {:?}", listed.ranges());
+    Ok(())
+}
+
+fn parse_selector(selector: &str) -> Result<NodeSelector, CommandError> {
+    selector
+        .parse::<NodeSelector>()
+        .map_err(|err| CommandError::Failed(err.into()))
+}
+
+/// Splits `<from> <to>` node selectors off the front of `args` if at least `min_rest` arguments follow them. Without them,
+/// the ghosts go from the A nodes to the Z nodes.
+fn split_selectors<'a>(args: &'a [&'a str], min_rest: usize) -> ([&'a str; 2], &'a [&'a str]) {
+    match args {
+        [from, to, rest @ ..] if rest.len() >= min_rest => ([from, to], rest),
+        _ => (["suffix:A", "suffix:Z"], args),
+    }
+}
+
+fn day8_jump(args: &[&str]) -> Result<(), CommandError> {
+    let ([from, to], args) = split_selectors(args, 1);
+    let (starts, goals) = (parse_selector(from)?, parse_selector(to)?);
+    let [steps] = parse_args(args)?;
+    let day8: Day8 = load_solver(8)?;
+    let ghosts = day8.jump(&starts, &goals, steps);
+    if ghosts.is_empty() {
+        return Err(CommandError::Failed(format!("no node matches {from}").into()));
+    }
+    for ghost in ghosts.iter() {
+        match ghost.next_z_step {
+            NextZStep::At(z_step) => println!(
+                "{} is at {} after {steps} steps, next on a node matching {to} after {z_step} steps",
+                ghost.start, ghost.node
+            ),
+            NextZStep::BeyondRange => println!(
+                "{} is at {} after {steps} steps, and next on a node matching {to} after more than {} steps",
+                ghost.start,
+                ghost.node,
+                u64::MAX
+            ),
+            NextZStep::Never => println!(
+                "{} is at {} after {steps} steps, and never on a node matching {to} again",
+                ghost.start, ghost.node
+            ),
+        }
+    }
+    if ghosts.iter().all(|ghost| ghost.next_z_step == NextZStep::At(steps)) {
+        println!("Every ghost is on a node matching {to} after {steps} steps");
+    } else {
+        println!("Not every ghost is on a node matching {to} after {steps} steps");
+    }
+    Ok(())
+}
+
+fn day8_dot(args: &[&str]) -> Result<(), CommandError> {
+    let ([from, to], args) = split_selectors(args, 0);
+    let (starts, goals) = (parse_selector(from)?, parse_selector(to)?);
+    let color_cycles = match args {
+        [] => false,
+        ["cycles"] => true,
+        _ => return Err(CommandError::InvalidArguments),
+    };
+    let day8: Day8 = load_solver(8)?;
+    println!("{}", day8.dot(&starts, &goals, color_cycles));
+    Ok(())
+}
+
+/// Returns prime factors along with their exponents like `2^3 × 5`, or `1` if there are none.
+fn factors_text(factors: &[(u64, u32)]) -> String {
+    if factors.is_empty() {
+        return "1".to_owned();
+    }
+    let factors: Vec<String> = factors
+        .iter()
+        .map(|&(prime, exponent)| match exponent {
+            1 => prime.to_string(),
+            _ => format!("{prime}^{exponent}"),
+        })
+        .collect();
+    factors.join(" × ")
+}
+
+/// Returns prime factors along with their exponents as a JSON array of `[prime, exponent]` pairs.
+fn factors_json(factors: &[(u64, u32)]) -> String {
+    let factors: Vec<String> = factors
+        .iter()
+        .map(|(prime, exponent)| format!("[{prime},{exponent}]"))
+        .collect();
+    format!("[{}]", factors.join(","))
+}
+
+/// Prints the report as lines, naming the Z nodes after the `to` selector that matched them.
+fn print_cycle_report_text(report: &CycleReport, to: &str) {
+    println!(
+        "L/R sequence of {} instructions = {}",
+        report.dir_count,
+        factors_text(&report.dir_count_factors)
+    );
+    for ghost in report.ghosts.iter() {
+        println!(
+            "{}: lead-in of {} steps, cycle of {} steps = {} × {}",
+            ghost.start,
+            ghost.lead_in,
+            ghost.cycle_length,
+            report.dir_count,
+            factors_text(&ghost.dir_multiple_factors)
+        );
+        if ghost.z_nodes.is_empty() {
+            println!("    never on a node matching {to} in its cycle");
+        }
+        for (step, id) in ghost.z_nodes.iter() {
+            println!("    on {id} after {step} steps");
+        }
+    }
+}
+
+fn print_cycle_report_json(report: &CycleReport) {
+    let ghosts: Vec<String> = report
+        .ghosts
+        .iter()
+        .map(|ghost| {
+            let z_nodes: Vec<String> = ghost
+                .z_nodes
+                .iter()
+                .map(|(step, id)| format!("{{\"step\":{step},\"id\":{}}}", json_string(id)))
+                .collect();
+            format!(
+                "{{\"start\":{},\"lead_in\":{},\"cycle_length\":{},\"dir_multiple\":{},\"dir_multiple_factors\":{},\"z_nodes\":[{}]}}",
+                json_string(&ghost.start),
+                ghost.lead_in,
+                ghost.cycle_length,
+                ghost.dir_multiple,
+                factors_json(&ghost.dir_multiple_factors),
+                z_nodes.join(",")
+            )
+        })
+        .collect();
+    println!(
+        "{{\"dir_count\":{},\"dir_count_factors\":{},\"ghosts\":[{}]}}",
+        report.dir_count,
+        factors_json(&report.dir_count_factors),
+        ghosts.join(",")
+    );
+}
+
+fn day8_analyze(args: &[&str], format: Format) -> Result<(), CommandError> {
+    let ([from, to], args) = split_selectors(args, 0);
+    if !args.is_empty() {
+        return Err(CommandError::InvalidArguments);
+    }
+    let (starts, goals) = (parse_selector(from)?, parse_selector(to)?);
+    let day8: Day8 = load_solver(8)?;
+    let report = day8.analyze(&starts, &goals);
+    match format {
+        Format::Text => print_cycle_report_text(&report, to),
+        Format::Json => print_cycle_report_json(&report),
+    }
+    Ok(())
+}
+
+fn day8_steps(args: &[&str]) -> Result<(), CommandError> {
+    let &[from, to] = args else {
+        return Err(CommandError::InvalidArguments);
+    };
+    let (starts, goals) = (parse_selector(from)?, parse_selector(to)?);
+    let day8: Day8 = load_solver(8)?;
+    let walks = day8.walks(&starts, &goals);
+    if walks.is_empty() {
+        return Err(CommandError::Failed(format!("no node matches {from}").into()));
+    }
+    for walk in walks.iter() {
+        match &walk.goal {
+            Some((steps, goal)) => println!("{} reaches {goal} after {steps} steps", walk.start),
+            None => println!("{} never reaches a node matching {to}", walk.start),
+        }
+    }
+    if walks.len() > 1 {
+        match day8.first_common_goal_step(&starts, &goals) {
+            CommonGoalStep::At(steps) => println!("Every walk is on a node matching {to} after {steps} steps"),
+            CommonGoalStep::BeyondRange => {
+                println!("Every walk is on a node matching {to} after more than {} steps", u64::MAX)
+            }
+            CommonGoalStep::Never => println!("The walks are never on nodes matching {to} at the same time"),
+            CommonGoalStep::Undetermined => {
+                println!("Could not tell whether the walks are ever on nodes matching {to} at the same time")
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Runs the day-specific `command` with `args` on the input of `day`, printing its output in `format`.
+pub fn run(day: u8, command: &str, args: &[&str], format: Format) -> Result<(), CommandError> {
+    let text_only = |command: fn(&[&str]) -> Result<(), CommandError>| match format {
+        Format::Text => command(args),
+        _ => Err(CommandError::UnsupportedFormat(format)),
+    };
+    match (day, command) {
+        (5, "trace") => text_only(day5_trace),
+        (5, "seeds") => text_only(day5_seeds),
+        (8, "jump") => text_only(day8_jump),
+        (8, "dot") => text_only(day8_dot),
+        (8, "analyze") => day8_analyze(args, format),
+        (8, "steps") => text_only(day8_steps),
+        _ => Err(CommandError::UnknownCommand),
+    }
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::UnknownCommand => write!(f, "unknown command"),
+            CommandError::InvalidArguments => write!(f, "invalid arguments"),
+            CommandError::BatchInput => write!(f, "commands need a single input file, not a directory"),
+            CommandError::Io(err) => write!(f, "could not read input: {err}"),
+            CommandError::UnsupportedFormat(format) => write!(f, "this command has no {format} output"),
+            CommandError::Failed(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for CommandError {}
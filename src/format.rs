@@ -0,0 +1,63 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// Output formats of the runner and of commands that report structured data, chosen with `--format=<text|json>`.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum Format {
+    /// Lines meant for reading.
+    #[default]
+    Text,
+    /// JSON values, meant for other tools.
+    Json,
+}
+
+/// Error returned when parsing a [`Format`] that doesn't exist.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct UnknownFormatError(String);
+
+impl FromStr for Format {
+    type Err = UnknownFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(UnknownFormatError(s.to_owned())),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl Display for UnknownFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown format '{}', expected text or json", self.0)
+    }
+}
+
+impl Error for UnknownFormatError {}
+
+/// Returns `s` as a JSON string.
+pub fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            ch if ch.is_control() => json.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+    json
+}
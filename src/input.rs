@@ -0,0 +1,92 @@
+use std::{fs, io, path::Path};
+
+const INPUT_DIR: &str = "input";
+const EXPECTED_EXTENSION: &str = "expected";
+
+/// A named puzzle input, along with the answers it should produce if they are known.
+pub struct Input {
+    pub name: String,
+    pub text: String,
+    /// Expected answers to part 1 and part 2, read from a file named like the input with `.expected` appended. The
+    /// first line holds the part 1 answer and the second line the part 2 answer. Missing or empty lines are unknown answers.
+    pub expected: [Option<String>; 2],
+}
+
+/// The puzzle inputs of a single day.
+pub enum DayInputs {
+    /// The contents of the file `input/dayN`.
+    Single(String),
+    /// Every input in the directory `input/dayN/`, sorted by name.
+    Batch(Vec<Input>),
+}
+
+impl Input {
+    /// Compares the answers against the expected ones, returning a short description of the result.
+    pub fn check(&self, answers: [&str; 2]) -> String {
+        if self.expected.iter().all(Option::is_none) {
+            return "-".to_owned();
+        }
+        let mismatches: Vec<String> = self
+            .expected
+            .iter()
+            .zip(answers)
+            .enumerate()
+            .filter_map(|(part, (expected, answer))| match expected {
+                Some(expected) if expected != answer => Some(format!("part {} should be {expected}", part + 1)),
+                _ => None,
+            })
+            .collect();
+        if mismatches.is_empty() {
+            "ok".to_owned()
+        } else {
+            format!("FAIL: {}", mismatches.join(", "))
+        }
+    }
+}
+
+fn read_expected(path: &Path) -> io::Result<[Option<String>; 2]> {
+    let mut expected = [None, None];
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(expected),
+        Err(err) => return Err(err),
+    };
+    for (answer, line) in expected.iter_mut().zip(text.lines()) {
+        let line = line.trim();
+        if !line.is_empty() {
+            *answer = Some(line.to_owned());
+        }
+    }
+    Ok(expected)
+}
+
+fn read_batch(dir: &Path) -> io::Result<Vec<Input>> {
+    let mut inputs = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() || path.extension().is_some_and(|ext| ext == EXPECTED_EXTENSION) {
+            continue;
+        }
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        let text = fs::read_to_string(&path)?;
+        let expected = read_expected(&path.with_file_name(format!("{name}.{EXPECTED_EXTENSION}")))?;
+        inputs.push(Input { name, text, expected });
+    }
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+/// Reads the inputs of `day`, either from the file `input/dayN` or from every file in the directory `input/dayN/`.
+pub fn load(day: u8) -> io::Result<DayInputs> {
+    load_from(Path::new(INPUT_DIR), day)
+}
+
+/// Reads the inputs of `day` like [`load`], but from `dir` instead of `input`.
+pub fn load_from(dir: &Path, day: u8) -> io::Result<DayInputs> {
+    let path = dir.join(format!("day{day}"));
+    if path.is_dir() {
+        Ok(DayInputs::Batch(read_batch(&path)?))
+    } else {
+        Ok(DayInputs::Single(fs::read_to_string(path)?))
+    }
+}
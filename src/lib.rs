@@ -0,0 +1,14 @@
+//! Solutions to Advent of Code 2023, along with the utilities they're built on.
+//!
+//! Every day has a solver in [`solutions`] implementing [`Solver`](solutions::Solver), so solving an input is as simple as
+//! `Day5::solve(input)`, which returns the answers to both parts. The command line runner is built on [`runner`], and the
+//! day-specific commands (like tracing a seed through Day 5's almanac) on [`commands`].
+
+pub mod cache;
+pub mod commands;
+pub mod format;
+pub mod input;
+pub mod runner;
+pub mod solutions;
+
+pub use aoc_util as util;
@@ -1,17 +1,17 @@
 use std::{env, io::Error};
 
-use crate::solutions::{
-    day1::Day1, day2::Day2, day3::Day3, day4::Day4, day5::Day5, day6::Day6, day7::Day7, day8::Day8, day9::Day9, Solver,
+use aoc2023::{
+    cache::{Cache, CACHE_DIR_VAR},
+    commands::{self, CommandError},
+    format::Format,
+    runner,
 };
 
-pub mod solutions;
-pub mod util;
-
 #[derive(Debug)]
 enum ProcessNameError {
     NoFile,
     NotUtf8,
-    Io(Error),
+    Io(#[allow(dead_code)] Error),
 }
 
 impl From<Error> for ProcessNameError {
@@ -30,50 +30,95 @@ fn get_process_name() -> Result<String, ProcessNameError> {
 }
 
 fn print_usage() {
-    println!("Usage: {} <all|1-25> [repeat_count]", get_process_name().unwrap());
+    let process_name = get_process_name().unwrap();
+    println!("Usage: {process_name} <all|1-25> [repeat_count] [--no-cache] [--format=<text|json>]");
+    println!("       {process_name} clear-cache [1-25]");
+    for usage in commands::USAGE {
+        println!("       {process_name} {usage}");
+    }
 }
 
-fn run_solver(day: u8, repeat_count: u32) {
-    println!("\nSolving day {day}:");
-    match day {
-        1 => Day1::run(repeat_count),
-        2 => Day2::run(repeat_count),
-        3 => Day3::run(repeat_count),
-        4 => Day4::run(repeat_count),
-        5 => Day5::run(repeat_count),
-        6 => Day6::run(repeat_count),
-        7 => Day7::run(repeat_count),
-        8 => Day8::run(repeat_count),
-        9 => Day9::run(repeat_count),
-        _ => unreachable!(),
+fn parse_day(arg: &str) -> Option<u8> {
+    arg.parse::<u8>().ok().filter(|day| (1..=25).contains(day))
+}
+
+fn clear_cache(args: &[&str]) {
+    let day = match args {
+        [] => None,
+        [day] => match parse_day(day) {
+            Some(day) => Some(day),
+            None => return print_usage(),
+        },
+        _ => return print_usage(),
+    };
+    let Some(cache) = Cache::from_env() else {
+        return println!("The answer cache is disabled, set {CACHE_DIR_VAR} to enable it");
+    };
+    match cache.clear(day) {
+        Ok(removed) => println!("Removed {removed} cached answers"),
+        Err(err) => println!("Could not clear the answer cache: {err}"),
+    }
+}
+
+fn run_command(args: &[&str], format: Format) {
+    let Some(day) = parse_day(args[0]) else {
+        return print_usage();
+    };
+    match commands::run(day, args[1], &args[2..], format) {
+        Ok(()) => (),
+        Err(CommandError::UnknownCommand | CommandError::InvalidArguments) => print_usage(),
+        Err(err) => println!("Could not run command: {err}"),
     }
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 || args.len() >= 4 {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (flags, args): (Vec<&str>, Vec<&str>) = args.iter().map(String::as_str).partition(|arg| arg.starts_with("--"));
+
+    let mut use_cache = true;
+    let mut format = Format::default();
+    for flag in flags {
+        match flag.split_once('=') {
+            None if flag == "--no-cache" => use_cache = false,
+            Some(("--format", value)) => match value.parse() {
+                Ok(parsed_format) => format = parsed_format,
+                Err(err) => return println!("{err}"),
+            },
+            _ => return print_usage(),
+        }
+    }
+
+    if args.first() == Some(&"clear-cache") {
+        return clear_cache(&args[1..]);
+    }
+    if args.len() >= 2 && args[1].parse::<u32>().is_err() {
+        return run_command(&args, format);
+    }
+    if args.is_empty() || args.len() >= 3 {
         return print_usage();
     }
 
     let mut repeat_count: u32 = 1;
-    if args.len() >= 3 {
-        if let Ok(parsed_repeat_count) = args[2].parse::<u32>() {
+    if args.len() >= 2 {
+        if let Ok(parsed_repeat_count) = args[1].parse::<u32>() {
             repeat_count = parsed_repeat_count;
         } else {
             return print_usage();
         }
     }
 
-    if args[1] == "all" {
+    let cache = if use_cache { Cache::from_env() } else { None };
+
+    if args[0] == "all" {
+        // Days without a solver yet are skipped.
         for day in 1..=25 {
-            run_solver(day, repeat_count);
+            let _ = runner::run_day(day, repeat_count, cache.as_ref(), format);
         }
-    } else if let Ok(day) = args[1].parse::<u8>() {
-        if !(1..=25).contains(&day) {
-            return print_usage();
+    } else if let Some(day) = parse_day(args[0]) {
+        if let Err(err) = runner::run_day(day, repeat_count, cache.as_ref(), format) {
+            println!("Could not solve: {err}");
         }
-        run_solver(day, repeat_count);
     } else {
-        return print_usage();
+        print_usage();
     }
 }
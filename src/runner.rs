@@ -0,0 +1,179 @@
+use std::{
+    any::type_name,
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use crate::{
+    cache::Cache,
+    format::{json_string, Format},
+    input::{self, DayInputs, Input},
+    solutions::{
+        day1::Day1, day2::Day2, day3::Day3, day4::Day4, day5::Day5, day6::Day6, day7::Day7, day8::Day8, day9::Day9, Solver,
+    },
+};
+
+/// Error returned when running a day that has no solver yet.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct UnsolvedDayError(pub u8);
+
+const HEADERS: [&str; 7] = [
+    "Input",
+    "Part 1",
+    "Part 2",
+    "Parse time",
+    "Part 1 time",
+    "Part 2 time",
+    "Check",
+];
+
+fn print_table(rows: &[[String; HEADERS.len()]]) {
+    let mut widths = HEADERS.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |cells: &[&str]| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", line.join(" | ").trim_end());
+    };
+    print_row(&HEADERS);
+    println!("{}", widths.map(|width| "-".repeat(width)).join("-+-"));
+    for row in rows {
+        print_row(&row.each_ref().map(String::as_str));
+    }
+}
+
+/// Returns the cached answers to part 1 and part 2 of `input` for the solver `S`. Runs that solve more than once are meant
+/// for timing the solver, so they never use cached answers.
+fn cached_answers<S: Solver>(cache: Option<&Cache>, day: u8, input: &str, repeat_count: u32) -> Option<[String; 2]> {
+    if repeat_count > 1 {
+        return None;
+    }
+    cache?.get(day, type_name::<S>(), input)
+}
+
+fn cache_answers<S: Solver>(cache: Option<&Cache>, day: u8, input: &str, answers: [&str; 2]) {
+    if let Some(cache) = cache {
+        if let Err(err) = cache.insert(day, type_name::<S>(), input, answers) {
+            println!("Could not cache answers: {err}");
+        }
+    }
+}
+
+fn run_single<S: Solver>(day: u8, input: &str, repeat_count: u32, cache: Option<&Cache>) {
+    let cached = cached_answers::<S>(cache, day, input, repeat_count)
+        .and_then(|[part1, part2]| Some((part1.parse().ok()?, part2.parse().ok()?)));
+    if let Some((part1, part2)) = cached {
+        S::new().print_solutions(part1, part2);
+        println!("(cached)");
+        return;
+    }
+
+    let [part1, part2] = S::run(input, repeat_count);
+    cache_answers::<S>(cache, day, input, [&part1, &part2]);
+}
+
+/// Prints the rows of a day as a single line of JSON, with a key for every header.
+fn print_json(day: u8, rows: &[[String; HEADERS.len()]]) {
+    let keys = HEADERS.map(|header| header.to_lowercase().replace(' ', "_"));
+    let inputs: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let fields: Vec<String> = keys
+                .iter()
+                .zip(row)
+                .map(|(key, cell)| format!("\"{key}\":{}", json_string(cell)))
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        })
+        .collect();
+    println!("{{\"day\":{day},\"inputs\":[{}]}}", inputs.join(","));
+}
+
+fn run_batch<S: Solver>(day: u8, inputs: &[Input], repeat_count: u32, cache: Option<&Cache>, format: Format) {
+    let rows: Vec<[String; HEADERS.len()]> = inputs
+        .iter()
+        .map(|input| {
+            let ([part1, part2], timings) = match cached_answers::<S>(cache, day, &input.text, repeat_count) {
+                Some(answers) => (answers, ["cached".to_owned(), "cached".to_owned(), "cached".to_owned()]),
+                None => {
+                    let (part1, part2, timings) = S::run_timed(&input.text, repeat_count);
+                    let answers = [part1.to_string(), part2.to_string()];
+                    cache_answers::<S>(cache, day, &input.text, [&answers[0], &answers[1]]);
+                    let timings = [timings.parse, timings.part1, timings.part2].map(|time| format!("{time:?}"));
+                    (answers, timings)
+                }
+            };
+            let check = input.check([&part1, &part2]);
+            let [parse_time, part1_time, part2_time] = timings;
+            [input.name.clone(), part1, part2, parse_time, part1_time, part2_time, check]
+        })
+        .collect();
+    match format {
+        Format::Text => print_table(&rows),
+        Format::Json => print_json(day, &rows),
+    }
+}
+
+/// Solves the inputs of `day` using the solver `S`, printing the results in `format`.
+///
+/// As text, a single input gets its solutions printed by the solver, while a directory of inputs gets a table of answers and
+/// timings. As JSON, every input gets an object with the same fields as a row of that table. Answers found in `cache` are
+/// shown without solving unless `repeat_count` is above 1, and new answers are stored in it.
+pub fn run<S: Solver>(day: u8, repeat_count: u32, cache: Option<&Cache>, format: Format) {
+    match (input::load(day), format) {
+        (Ok(DayInputs::Single(input)), Format::Text) => run_single::<S>(day, &input, repeat_count, cache),
+        (Ok(DayInputs::Single(text)), Format::Json) => {
+            let input = Input {
+                name: format!("day{day}"),
+                text,
+                expected: [None, None],
+            };
+            run_batch::<S>(day, &[input], repeat_count, cache, format)
+        }
+        (Ok(DayInputs::Batch(inputs)), _) => run_batch::<S>(day, &inputs, repeat_count, cache, format),
+        (Err(err), Format::Text) => println!("Could not read input: {err}"),
+        (Err(err), Format::Json) => println!(
+            "{{\"day\":{day},\"error\":{}}}",
+            json_string(&format!("could not read input: {err}"))
+        ),
+    }
+}
+
+/// Solves the inputs of `day` using its solver, as described in [`run`]. Text output starts with a header naming the day.
+///
+/// Returns an error without printing anything if `day` has no solver.
+pub fn run_day(day: u8, repeat_count: u32, cache: Option<&Cache>, format: Format) -> Result<(), UnsolvedDayError> {
+    let run: fn(u8, u32, Option<&Cache>, Format) = match day {
+        1 => run::<Day1>,
+        2 => run::<Day2>,
+        3 => run::<Day3>,
+        4 => run::<Day4>,
+        5 => run::<Day5>,
+        6 => run::<Day6>,
+        7 => run::<Day7>,
+        8 => run::<Day8>,
+        9 => run::<Day9>,
+        _ => return Err(UnsolvedDayError(day)),
+    };
+    if format == Format::Text {
+        println!("\nSolving day {day}:");
+    }
+    run(day, repeat_count, cache, format);
+    Ok(())
+}
+
+impl Display for UnsolvedDayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {} has no solver yet", self.0)
+    }
+}
+
+impl Error for UnsolvedDayError {}
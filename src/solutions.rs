@@ -1,3 +1,9 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
 pub mod day1;
 pub mod day2;
 pub mod day3;
@@ -8,30 +14,86 @@ pub mod day7;
 pub mod day8;
 pub mod day9;
 
+/// Average time spent on each step of solving an input.
+#[derive(Default, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
 pub trait Solver {
-    type Solution1;
-    type Solution2;
+    type Solution1: Display + FromStr;
+    type Solution2: Display + FromStr;
 
     fn new() -> Self;
     fn reset(&mut self);
-    fn parse_input(&mut self);
+    fn parse_input(&mut self, input: &str);
     fn solve_part1(&self) -> Self::Solution1;
     fn solve_part2(&self) -> Self::Solution2;
     fn print_solutions(&self, part1: Self::Solution1, part2: Self::Solution2);
 
-    fn run(repeat_count: u32)
+    /// Parses and solves `input`, returning the solutions to part 1 and part 2.
+    fn solve(input: &str) -> (Self::Solution1, Self::Solution2)
+    where
+        Self: Sized,
+    {
+        let mut solver = Self::new();
+        solver.parse_input(input);
+        (solver.solve_part1(), solver.solve_part2())
+    }
+
+    /// Parses and solves `input` `repeat_count` times (at least once), printing the last solutions using the solver that
+    /// found them. Returns those solutions as strings.
+    fn run(input: &str, repeat_count: u32) -> [String; 2]
     where
         Self: Sized,
     {
+        let repeat_count = repeat_count.max(1);
         let mut solver = Self::new();
+        let mut answers = [String::new(), String::new()];
         for i in 0..repeat_count {
-            solver.parse_input();
+            solver.parse_input(input);
             let part1 = solver.solve_part1();
             let part2 = solver.solve_part2();
             if i == repeat_count - 1 {
+                answers = [part1.to_string(), part2.to_string()];
                 solver.print_solutions(part1, part2);
             }
             solver.reset();
         }
+        answers
+    }
+
+    /// Parses and solves `input` `repeat_count` times (at least once), returning the last solutions along with the average
+    /// time spent on each step.
+    fn run_timed(input: &str, repeat_count: u32) -> (Self::Solution1, Self::Solution2, Timings)
+    where
+        Self: Sized,
+    {
+        let repeat_count = repeat_count.max(1);
+        let mut solver = Self::new();
+        let mut timings = Timings::default();
+        let mut solutions = None;
+        for _ in 0..repeat_count {
+            let start = Instant::now();
+            solver.parse_input(input);
+            let parsed = Instant::now();
+            let part1 = solver.solve_part1();
+            let solved_part1 = Instant::now();
+            let part2 = solver.solve_part2();
+            let solved_part2 = Instant::now();
+            solver.reset();
+
+            timings.parse += parsed - start;
+            timings.part1 += solved_part1 - parsed;
+            timings.part2 += solved_part2 - solved_part1;
+            solutions = Some((part1, part2));
+        }
+        let (part1, part2) = solutions.unwrap();
+        timings.parse /= repeat_count;
+        timings.part1 /= repeat_count;
+        timings.part2 /= repeat_count;
+        (part1, part2, timings)
     }
 }
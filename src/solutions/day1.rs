@@ -1,11 +1,9 @@
 use super::Solver;
 
-pub struct Day1<'a> {
-    lines: Vec<&'a str>,
+pub struct Day1 {
+    lines: Vec<String>,
 }
 
-const INPUT: &str = include_str!("../../input/day1");
-
 fn get_digit<Iter>(iter: &mut Iter) -> Option<u32>
 where
     Iter: Iterator<Item = char> + Sized,
@@ -40,7 +38,7 @@ where
     })
 }
 
-impl<'a> Solver for Day1<'a> {
+impl Solver for Day1 {
     type Solution1 = u32;
     type Solution2 = u32;
 
@@ -52,8 +50,8 @@ impl<'a> Solver for Day1<'a> {
         self.lines.clear();
     }
 
-    fn parse_input(&mut self) {
-        self.lines = INPUT.lines().collect();
+    fn parse_input(&mut self, input: &str) {
+        self.lines = input.lines().map(str::to_owned).collect();
     }
 
     fn solve_part1(&self) -> u32 {
@@ -24,8 +24,6 @@ pub struct Day2 {
     games: Vec<Game>,
 }
 
-const INPUT: &str = include_str!("../../input/day2");
-
 impl Solver for Day2 {
     type Solution1 = u32;
     type Solution2 = u32;
@@ -38,8 +36,8 @@ impl Solver for Day2 {
         self.games.clear();
     }
 
-    fn parse_input(&mut self) {
-        for line in INPUT.lines() {
+    fn parse_input(&mut self, input: &str) {
+        for line in input.lines() {
             let (id, subsets) = line.split_once(": ").unwrap();
             let (_, id) = id.split_once(' ').unwrap();
             let id: u32 = id.parse().unwrap();
@@ -1,4 +1,4 @@
-use crate::util::{intset::IntSet, set::Set};
+use crate::util::{bitset::BitSet, set::Set};
 
 use super::Solver;
 
@@ -11,11 +11,10 @@ pub struct Day4 {
     cards: Vec<Card>,
 }
 
-const INPUT: &str = include_str!("../../input/day4");
-
 impl Card {
     fn count_matching_numbers(&self) -> usize {
-        let mut winning_set = IntSet::new(0, 100);
+        // Numbers have at most two digits, which fit in 128 bits.
+        let mut winning_set = BitSet::<2>::new();
         self.winning_numbers.iter().for_each(|&num| winning_set.add(num.into()));
         self.drawn_numbers
             .iter()
@@ -47,8 +46,8 @@ impl Solver for Day4 {
         self.cards.clear();
     }
 
-    fn parse_input(&mut self) {
-        for line in INPUT.lines() {
+    fn parse_input(&mut self, input: &str) {
+        for line in input.lines() {
             let (_, numbers) = line.split_once(':').unwrap();
             let (winning_numbers, drawn_numbers) = numbers.split_once('|').unwrap();
             let winning_numbers = Day4::parse_numbers(winning_numbers);
@@ -1,39 +1,346 @@
-use std::fmt::Debug;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use crate::util::interval::IntervalSet;
 
 use super::Solver;
 
 type Seed = i64;
 type Length = i64;
 
-#[derive(Clone, Copy)]
-struct SeedRange {
-    min: Seed,
+#[derive(Debug)]
+struct Range {
+    dst_start: Seed,
+    src_start: Seed,
     length: Length,
 }
 
-impl Debug for SeedRange {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}..{}", self.min, self.min + self.length)
+/// A part of a [`Map`] which adds `offset` to every value from `start` until the start of the next piece.
+#[derive(Clone, Copy, Debug)]
+struct Piece {
+    start: Seed,
+    offset: Seed,
+    /// Index of the range rule this piece comes from, or `None` for values outside of any rule and for composed maps.
+    rule: Option<usize>,
+}
+
+/// A piecewise function which adds a constant offset to the values of each piece.
+///
+/// The pieces are sorted by their start and cover every value, with the first one starting at `Seed::MIN` and the last one
+/// ending at `Seed::MAX`. Values outside of any range rule get an offset of 0, so pieces with other offsets are always
+/// bounded, which keeps the offset arithmetic from overflowing.
+#[derive(Debug)]
+struct Map {
+    pieces: Vec<Piece>,
+    /// The range rules this map was built from, empty for composed maps.
+    rules: Vec<Range>,
+}
+
+impl Map {
+    /// Returns the map which leaves every value unchanged.
+    fn identity() -> Map {
+        Map {
+            pieces: vec![Piece {
+                start: Seed::MIN,
+                offset: 0,
+                rule: None,
+            }],
+            rules: vec![],
+        }
+    }
+
+    /// Builds a map from its range rules. When rules overlap, the first one wins.
+    fn from_ranges(ranges: Vec<Range>) -> Map {
+        let mut covered = IntervalSet::new();
+        let mut bounded: Vec<(std::ops::Range<Seed>, Seed, usize)> = vec![];
+        for (rule, range) in ranges.iter().enumerate() {
+            let src = IntervalSet::from(range.src_start..range.src_start + range.length);
+            let offset = range.dst_start - range.src_start;
+            bounded.extend(
+                src.difference(&covered)
+                    .ranges()
+                    .iter()
+                    .map(|src| (src.clone(), offset, rule)),
+            );
+            covered = covered.union(&src);
+        }
+        bounded.sort_unstable_by_key(|(src, _, _)| src.start);
+
+        let unmapped = |start| Piece {
+            start,
+            offset: 0,
+            rule: None,
+        };
+        let mut pieces = vec![];
+        let mut end = Seed::MIN;
+        for (src, offset, rule) in bounded {
+            if end < src.start {
+                pieces.push(unmapped(end));
+            }
+            pieces.push(Piece {
+                start: src.start,
+                offset,
+                rule: Some(rule),
+            });
+            end = src.end;
+        }
+        pieces.push(unmapped(end));
+        Map::merged(pieces, ranges)
+    }
+
+    /// Creates a map from sorted `pieces`, merging neighboring pieces with the same offset and rule.
+    fn merged(pieces: Vec<Piece>, rules: Vec<Range>) -> Map {
+        let mut merged: Vec<Piece> = Vec::with_capacity(pieces.len());
+        for piece in pieces {
+            match merged.last_mut() {
+                Some(last) if last.start == piece.start => *last = piece,
+                Some(last) if last.offset == piece.offset && last.rule == piece.rule => (),
+                _ => merged.push(piece),
+            }
+        }
+        Map { pieces: merged, rules }
+    }
+
+    /// Returns the values covered by the piece at `index`.
+    fn domain(&self, index: usize) -> std::ops::Range<Seed> {
+        let end = self.pieces.get(index + 1).map_or(Seed::MAX, |next| next.start);
+        self.pieces[index].start..end
+    }
+
+    /// Returns the index of the piece containing `value`.
+    fn piece_index(&self, value: Seed) -> usize {
+        self.pieces.partition_point(|piece| piece.start <= value) - 1
+    }
+
+    fn apply(&self, value: Seed) -> Seed {
+        value + self.pieces[self.piece_index(value)].offset
+    }
+
+    /// Returns the map which applies `self` followed by `next`.
+    fn then(&self, next: &Map) -> Map {
+        let mut pieces = vec![];
+        for (index, piece) in self.pieces.iter().enumerate() {
+            let domain = self.domain(index);
+            let image = domain.start.saturating_add(piece.offset)..domain.end.saturating_add(piece.offset);
+            for next_index in next.piece_index(image.start)..next.pieces.len() {
+                let next_piece = next.pieces[next_index];
+                if next_piece.start >= image.end {
+                    break;
+                }
+                pieces.push(Piece {
+                    start: domain.start.max(next_piece.start.saturating_sub(piece.offset)),
+                    offset: piece.offset + next_piece.offset,
+                    rule: None,
+                });
+            }
+        }
+        Map::merged(pieces, vec![])
+    }
+
+    /// Returns the values that `values` are mapped to.
+    fn image(&self, values: &IntervalSet<Seed>) -> IntervalSet<Seed> {
+        let mut image = IntervalSet::new();
+        for range in values.ranges() {
+            for index in self.piece_index(range.start)..self.pieces.len() {
+                let domain = self.domain(index);
+                if domain.start >= range.end {
+                    break;
+                }
+                let part = IntervalSet::from(domain.start.max(range.start)..domain.end.min(range.end));
+                image = image.union(&part.translated(self.pieces[index].offset));
+            }
+        }
+        image
+    }
+
+    /// Returns the values that are mapped to any of `values`, which makes this the inverse of [`Map::image`].
+    fn preimage(&self, values: &IntervalSet<Seed>) -> IntervalSet<Seed> {
+        let mut preimage = IntervalSet::new();
+        for (index, piece) in self.pieces.iter().enumerate() {
+            let image = IntervalSet::from(self.domain(index)).translated(piece.offset);
+            preimage = preimage.union(&values.intersection(&image).translated(-piece.offset));
+        }
+        preimage
     }
 }
 
+/// A map converting values of the category `source` into values of the category `destination`.
 #[derive(Debug)]
-struct Range {
-    dst_start: Seed,
-    src_start: Seed,
-    length: Length,
+struct Conversion {
+    source: String,
+    destination: String,
+    map: Map,
 }
 
-struct Map {
-    ranges: Vec<Range>,
+/// Error returned when looking for the conversions between two categories.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum CategoryError {
+    /// No map converts from or into this category.
+    Unknown(String),
+    /// No chain of maps converts from `from` into `to`.
+    Missing { from: String, to: String },
+    /// More than one chain of maps converts from `from` into `to`.
+    Ambiguous { from: String, to: String },
+}
+
+/// The maps of the almanac, as a graph with the categories as nodes and the maps as edges.
+struct CategoryGraph {
+    conversions: Vec<Conversion>,
+    /// Indices into `conversions` of the maps converting from each category.
+    by_source: HashMap<String, Vec<usize>>,
+}
+
+impl CategoryGraph {
+    fn new() -> CategoryGraph {
+        CategoryGraph {
+            conversions: vec![],
+            by_source: HashMap::new(),
+        }
+    }
+
+    fn add(&mut self, conversion: Conversion) {
+        self.by_source
+            .entry(conversion.source.clone())
+            .or_default()
+            .push(self.conversions.len());
+        self.conversions.push(conversion);
+    }
+
+    fn has_category(&self, category: &str) -> bool {
+        self.by_source.contains_key(category) || self.conversions.iter().any(|conversion| conversion.destination == category)
+    }
+
+    /// Collects up to two chains of conversions from `category` into `to` into `paths`, without visiting a category twice.
+    fn find_paths(&self, category: &str, to: &str, path: &mut Vec<usize>, paths: &mut Vec<Vec<usize>>) {
+        if category == to {
+            paths.push(path.clone());
+            return;
+        }
+        for &index in self.by_source.get(category).into_iter().flatten() {
+            let destination = &self.conversions[index].destination;
+            let visited = path.iter().any(|&step| {
+                self.conversions[step].source == *destination || self.conversions[step].destination == *destination
+            });
+            if visited || paths.len() >= 2 {
+                continue;
+            }
+            path.push(index);
+            self.find_paths(destination, to, path, paths);
+            path.pop();
+        }
+    }
+
+    /// Returns the indices of the conversions which convert `from` into `to` when applied in order.
+    fn path(&self, from: &str, to: &str) -> Result<Vec<usize>, CategoryError> {
+        if let Some(unknown) = [from, to].into_iter().find(|category| !self.has_category(category)) {
+            return Err(CategoryError::Unknown(unknown.to_owned()));
+        }
+        let mut paths = vec![];
+        self.find_paths(from, to, &mut vec![], &mut paths);
+        match paths.len() {
+            0 => Err(CategoryError::Missing {
+                from: from.to_owned(),
+                to: to.to_owned(),
+            }),
+            1 => Ok(paths.pop().unwrap()),
+            _ => Err(CategoryError::Ambiguous {
+                from: from.to_owned(),
+                to: to.to_owned(),
+            }),
+        }
+    }
+
+    /// Returns the single map which converts `from` into `to`.
+    fn composed(&self, from: &str, to: &str) -> Result<Map, CategoryError> {
+        Ok(self
+            .path(from, to)?
+            .into_iter()
+            .fold(Map::identity(), |composed, index| composed.then(&self.conversions[index].map)))
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.dst_start, self.src_start, self.length)
+    }
+}
+
+impl Display for CategoryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CategoryError::Unknown(category) => write!(f, "no map converts from or into {category}"),
+            CategoryError::Missing { from, to } => write!(f, "no chain of maps converts {from} into {to}"),
+            CategoryError::Ambiguous { from, to } => write!(f, "more than one chain of maps converts {from} into {to}"),
+        }
+    }
+}
+
+impl Error for CategoryError {}
+
+/// The value of a seed in one category, as traced by [`Day5::trace`].
+pub struct TraceStep {
+    pub category: String,
+    pub value: Seed,
+    /// The range rule which converted the value of the previous category into this one, like `rule 2 (52 50 48)`. `None`
+    /// for the seed itself and for values that no rule applied to, which keep their number.
+    pub rule: Option<String>,
 }
 
 pub struct Day5 {
     seeds: Vec<Seed>,
-    maps: Vec<Map>,
+    categories: CategoryGraph,
+    /// The maps from `seed` to `location` composed into one, or the error if they can't be found.
+    seed_to_location: Result<Map, CategoryError>,
 }
 
-const INPUT: &str = include_str!("../../input/day5");
+impl Day5 {
+    /// Returns the values of `value` in every category on the way from `from` to `to`, starting with `from` itself.
+    pub fn trace(&self, from: &str, to: &str, value: Seed) -> Result<Vec<TraceStep>, CategoryError> {
+        let mut steps = vec![TraceStep {
+            category: from.to_owned(),
+            value,
+            rule: None,
+        }];
+        let mut value = value;
+        for index in self.categories.path(from, to)? {
+            let Conversion { destination, map, .. } = &self.categories.conversions[index];
+            let piece = map.pieces[map.piece_index(value)];
+            value += piece.offset;
+            steps.push(TraceStep {
+                category: destination.clone(),
+                value,
+                rule: piece.rule.map(|rule| format!("rule {} ({})", rule + 1, map.rules[rule])),
+            });
+        }
+        Ok(steps)
+    }
+
+    /// Returns every seed that ends up in `locations`, regardless of whether the almanac lists it.
+    pub fn seeds_reaching(&self, locations: std::ops::Range<Seed>) -> Result<IntervalSet<Seed>, CategoryError> {
+        Ok(self
+            .seed_to_location
+            .as_ref()
+            .map_err(Clone::clone)?
+            .preimage(&IntervalSet::from(locations)))
+    }
+
+    /// Returns the map from `seed` to `location`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maps don't convert seeds into locations in exactly one way.
+    fn solution_map(&self) -> &Map {
+        self.seed_to_location.as_ref().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the seed ranges of the almanac, as used by part 2.
+    pub fn seed_ranges(&self) -> IntervalSet<Seed> {
+        self.seeds.chunks(2).map(|range| range[0]..range[0] + range[1]).collect()
+    }
+}
 
 impl Solver for Day5 {
     type Solution1 = Seed;
@@ -42,124 +349,55 @@ impl Solver for Day5 {
     fn new() -> Self {
         Day5 {
             seeds: vec![],
-            maps: vec![],
+            categories: CategoryGraph::new(),
+            seed_to_location: Ok(Map::identity()),
         }
     }
 
     fn reset(&mut self) {
         self.seeds.clear();
-        self.maps.clear();
+        self.categories = CategoryGraph::new();
+        self.seed_to_location = Ok(Map::identity());
     }
 
-    fn parse_input(&mut self) {
-        let (seeds, lines) = INPUT.split_once('\n').unwrap();
+    fn parse_input(&mut self, input: &str) {
+        let (seeds, lines) = input.split_once('\n').unwrap();
 
         let (_, seeds) = seeds.split_once(':').unwrap();
         self.seeds = seeds.trim().split(' ').map(|seed| seed.parse().unwrap()).collect();
-        self.maps = lines
-            .trim()
-            .split("\n\n")
-            .map(|map| {
-                let (_, ranges) = map.split_once('\n').unwrap();
-                let ranges: Vec<Range> = ranges
-                    .split('\n')
-                    .map(|range| {
-                        let (dst_start, rest) = range.split_once(' ').unwrap();
-                        let (src_start, length) = rest.split_once(' ').unwrap();
-                        let dst_start: Seed = dst_start.parse().unwrap();
-                        let src_start: Seed = src_start.parse().unwrap();
-                        let length: Length = length.parse().unwrap();
-                        Range {
-                            dst_start,
-                            src_start,
-                            length,
-                        }
-                    })
-                    .collect();
-                Map { ranges }
-            })
-            .collect();
+        for map in lines.trim().split("\n\n") {
+            let (header, ranges) = map.split_once('\n').unwrap();
+            let (source, destination) = header.trim_end_matches(" map:").split_once("-to-").unwrap();
+            let ranges: Vec<Range> = ranges
+                .split('\n')
+                .map(|range| {
+                    let (dst_start, rest) = range.split_once(' ').unwrap();
+                    let (src_start, length) = rest.split_once(' ').unwrap();
+                    let dst_start: Seed = dst_start.parse().unwrap();
+                    let src_start: Seed = src_start.parse().unwrap();
+                    let length: Length = length.parse().unwrap();
+                    Range {
+                        dst_start,
+                        src_start,
+                        length,
+                    }
+                })
+                .collect();
+            self.categories.add(Conversion {
+                source: source.to_owned(),
+                destination: destination.to_owned(),
+                map: Map::from_ranges(ranges),
+            });
+        }
+        self.seed_to_location = self.categories.composed("seed", "location");
     }
 
     fn solve_part1(&self) -> Seed {
-        let mut seeds = self.seeds.clone();
-        for map in self.maps.iter() {
-            for seed in seeds.iter_mut() {
-                let new_value = map
-                    .ranges
-                    .iter()
-                    .find_map(|range| {
-                        if *seed < range.src_start || *seed >= range.src_start + range.length {
-                            None
-                        } else {
-                            Some(*seed - range.src_start + range.dst_start)
-                        }
-                    })
-                    .unwrap_or(*seed);
-                *seed = new_value;
-            }
-        }
-
-        *seeds.iter().min().unwrap()
+        self.seeds.iter().map(|&seed| self.solution_map().apply(seed)).min().unwrap()
     }
 
     fn solve_part2(&self) -> Seed {
-        let mut seed_ranges: Vec<SeedRange> = self
-            .seeds
-            .chunks(2)
-            .map(|range| SeedRange {
-                min: range[0],
-                length: range[1],
-            })
-            .collect();
-        for map in self.maps.iter() {
-            seed_ranges = seed_ranges
-                .iter()
-                .flat_map(|seeds| {
-                    let (unmapped, mapped) =
-                        map.ranges
-                            .iter()
-                            .fold((vec![*seeds], vec![]), |(unmapped, mut mapped), range| {
-                                let mut new_unmapped = vec![];
-                                let range_max = range.src_start + range.length;
-                                for seeds in unmapped.iter() {
-                                    let seeds_max = seeds.min + seeds.length;
-                                    let min = std::cmp::max(seeds.min, range.src_start);
-                                    let max = std::cmp::min(seeds_max, range_max);
-                                    let length = max - min;
-                                    if length < 0 {
-                                        new_unmapped.push(*seeds);
-                                        continue;
-                                    }
-                                    mapped.push(SeedRange {
-                                        min: min - range.src_start + range.dst_start,
-                                        length: max - min,
-                                    });
-                                    if min > seeds.min {
-                                        new_unmapped.push(SeedRange {
-                                            min: seeds.min,
-                                            length: min - seeds.min,
-                                        })
-                                    }
-                                    if max < seeds_max {
-                                        new_unmapped.push(SeedRange {
-                                            min: max,
-                                            length: seeds_max - max,
-                                        });
-                                    }
-                                }
-                                (new_unmapped, mapped)
-                            });
-                    unmapped
-                        .iter()
-                        .copied()
-                        .chain(mapped.iter().copied())
-                        .collect::<Vec<SeedRange>>()
-                })
-                .collect();
-        }
-
-        seed_ranges.iter().map(|seeds| seeds.min).min().unwrap()
+        self.solution_map().image(&self.seed_ranges()).min().unwrap()
     }
 
     fn print_solutions(&self, part1: Seed, part2: Seed) {
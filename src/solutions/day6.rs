@@ -9,8 +9,6 @@ pub struct Day6 {
     races: Vec<Race>,
 }
 
-const INPUT: &str = include_str!("../../input/day6");
-
 impl Day6 {
     fn parse_line(line: &str) -> impl Iterator<Item = u64> + '_ {
         line.trim().split(' ').filter(|t| !t.is_empty()).map(|t| t.parse().unwrap())
@@ -63,8 +61,8 @@ impl Solver for Day6 {
         self.races.clear();
     }
 
-    fn parse_input(&mut self) {
-        let (times, records) = INPUT.split_once('\n').unwrap();
+    fn parse_input(&mut self, input: &str) {
+        let (times, records) = input.split_once('\n').unwrap();
         let (_, times) = times.split_once(':').unwrap();
         let (_, records) = records.split_once(':').unwrap();
         let times = Self::parse_line(times);
@@ -4,8 +4,6 @@ use crate::util::array::AsArray;
 
 use super::Solver;
 
-const INPUT: &str = include_str!("../../input/day7");
-
 #[derive(PartialEq, Clone)]
 struct Card(char);
 
@@ -199,8 +197,8 @@ impl Solver for Day7 {
         self.hands.clear();
     }
 
-    fn parse_input(&mut self) {
-        for line in INPUT.lines() {
+    fn parse_input(&mut self, input: &str) {
+        for line in input.lines() {
             let (cards, bid) = line.split_once(' ').unwrap();
             let cards = cards.as_array().map(Card);
             let bid = bid.parse().unwrap();
@@ -1,35 +1,44 @@
-use std::collections::HashSet;
+use std::str::FromStr;
 
-use crate::util::array::AsArray;
+use crate::util::{
+    cycle::{self, Cycle},
+    graph::Graph,
+    number::{checked_lcm_all, Gcd, Modular, Primes},
+    pattern::{Pattern, PatternError},
+};
 
 use super::Solver;
 
-type Id = [char; 3];
-
+#[derive(PartialEq, Eq, Clone, Copy)]
 enum Dir {
     Left,
     Right,
 }
 
-struct Node {
-    id: Id,
-    left: Id,
-    right: Id,
-}
-
 pub struct Day8 {
     dirs: Vec<Dir>,
-    nodes: Vec<Node>,
+    /// The network, with nodes in order of appearance and an edge labelled with each direction out of every node.
+    network: Graph<Dir>,
+}
+
+/// The state of a [`GraphWalker`], which is the index of its current node and the index of its next instruction.
+type WalkerState = (usize, usize);
+
+/// Returns the position of the edge that is followed from `state` among the edges out of its node.
+fn next_edge(network: &Graph<Dir>, dirs: &[Dir], (current, dir_index): WalkerState) -> usize {
+    let dir = dirs[dir_index];
+    network.edges(current).iter().position(|edge| edge.label == dir).unwrap()
 }
 
-struct GraphNode {
-    id: Id,
-    left: usize,
-    right: usize,
+/// Returns the state after walking once from `state`.
+fn next_state(network: &Graph<Dir>, dirs: &[Dir], state: WalkerState) -> WalkerState {
+    let (current, dir_index) = state;
+    let next = network.edges(current)[next_edge(network, dirs, state)].to;
+    (next, (dir_index + 1) % dirs.len())
 }
 
 struct GraphWalker<'a> {
-    nodes: &'a [GraphNode],
+    network: &'a Graph<Dir>,
     dirs: &'a [Dir],
 
     current: usize,
@@ -37,31 +46,10 @@ struct GraphWalker<'a> {
     dir_index: usize,
 }
 
-const INPUT: &str = include_str!("../../input/day8");
-
-fn node_index_by_id(nodes: &[Node], id: &Id) -> Option<usize> {
-    nodes
-        .iter()
-        .enumerate()
-        .find_map(|(index, other)| (id == &other.id).then_some(index))
-}
-
-fn graph_nodes(nodes: &[Node]) -> Vec<GraphNode> {
-    nodes
-        .iter()
-        .map(|node| {
-            let id = node.id;
-            let left = node_index_by_id(nodes, &node.left).unwrap();
-            let right = node_index_by_id(nodes, &node.right).unwrap();
-            GraphNode { id, left, right }
-        })
-        .collect()
-}
-
 impl<'a> GraphWalker<'a> {
-    fn new(nodes: &'a [GraphNode], dirs: &'a [Dir], start: usize) -> Self {
+    fn new(network: &'a Graph<Dir>, dirs: &'a [Dir], start: usize) -> Self {
         GraphWalker {
-            nodes,
+            network,
             dirs,
             current: start,
             steps: 0,
@@ -69,55 +57,557 @@ impl<'a> GraphWalker<'a> {
         }
     }
 
-    fn current_node(&self) -> &GraphNode {
-        &self.nodes[self.current]
+    fn state(&self) -> WalkerState {
+        (self.current, self.dir_index)
+    }
+
+    /// Returns the state after walking once from `state`, which doesn't need to be the current one.
+    fn next_state(&self, state: WalkerState) -> WalkerState {
+        next_state(self.network, self.dirs, state)
     }
 
     fn walk_once(&mut self) {
-        let next = match self.dirs[self.dir_index] {
-            Dir::Left => self.nodes[self.current].left,
-            Dir::Right => self.nodes[self.current].right,
-        };
+        (self.current, self.dir_index) = self.next_state(self.state());
         self.steps += 1;
+    }
 
-        self.dir_index += 1;
-        if self.dir_index >= self.dirs.len() {
-            self.dir_index = 0;
+    /// Walks until the current node is one that `is_goal` holds for, returning whether that happened. Stops at the first
+    /// repeated state otherwise, since the walker's path then repeats forever without reaching a goal.
+    fn walk_to<P: Fn(usize) -> bool>(&mut self, is_goal: P) -> bool {
+        let dir_count = self.dirs.len();
+        let mut visited = vec![false; self.network.len() * dir_count];
+        while !is_goal(self.current) {
+            let state = self.current * dir_count + self.dir_index;
+            if visited[state] {
+                return false;
+            }
+            visited[state] = true;
+            self.walk_once();
         }
+        true
+    }
+}
 
-        self.current = next;
+/// A binary lifting table over walker states, which jumps any number of steps ahead in `O(log steps)`.
+///
+/// States are numbered `node * dirs.len() + dir_index`. The table covers jumps of fewer than `2^levels` steps.
+struct JumpTable {
+    dir_count: usize,
+    /// `jumps[level][state]` is the state `2^level` steps after `state`.
+    jumps: Vec<Vec<u32>>,
+    /// `hits[level][state]` is whether the predicate holds for the node of any of the `2^level` states starting at `state`.
+    hits: Vec<Vec<bool>>,
+}
+
+impl JumpTable {
+    /// Builds a table for jumps of up to `max_steps` steps, marking the nodes which `predicate` holds for.
+    fn new<P: Fn(usize) -> bool>(network: &Graph<Dir>, dirs: &[Dir], max_steps: u64, predicate: P) -> JumpTable {
+        let dir_count = dirs.len();
+        let state_count = network.len() * dir_count;
+        let levels = (u64::BITS - max_steps.leading_zeros()).max(1) as usize;
+
+        let mut jumps = vec![(0..state_count)
+            .map(|state| {
+                let (node, dir_index) = next_state(network, dirs, (state / dir_count, state % dir_count));
+                (node * dir_count + dir_index) as u32
+            })
+            .collect::<Vec<u32>>()];
+        let mut hits = vec![(0..state_count)
+            .map(|state| predicate(state / dir_count))
+            .collect::<Vec<bool>>()];
+        for level in 1..levels {
+            let (previous_jumps, previous_hits) = (&jumps[level - 1], &hits[level - 1]);
+            let next_jumps = previous_jumps
+                .iter()
+                .map(|&halfway| previous_jumps[halfway as usize])
+                .collect();
+            let next_hits = (0..state_count)
+                .map(|state| previous_hits[state] || previous_hits[previous_jumps[state] as usize])
+                .collect();
+            jumps.push(next_jumps);
+            hits.push(next_hits);
+        }
+        JumpTable { dir_count, jumps, hits }
+    }
+
+    /// Returns the largest number of steps this table can jump at once.
+    fn max_steps(&self) -> u64 {
+        u64::MAX >> (u64::BITS as usize - self.jumps.len())
+    }
+
+    fn jump(&self, state: WalkerState, steps: u64) -> WalkerState {
+        assert!(steps <= self.max_steps(), "the jump table should cover {steps} steps");
+        let mut state = state.0 * self.dir_count + state.1;
+        for (level, jumps) in self.jumps.iter().enumerate() {
+            if steps & (1 << level) != 0 {
+                state = jumps[state] as usize;
+            }
+        }
+        (state / self.dir_count, state % self.dir_count)
+    }
+
+    /// Returns the first step from `from` onward at which the walker starting at `state` is on a node matching the predicate,
+    /// or `None` if that doesn't happen within the steps this table covers.
+    fn first_hit(&self, state: WalkerState, from: u64) -> Option<u64> {
+        let (node, dir_index) = self.jump(state, from);
+        let mut state = node * self.dir_count + dir_index;
+        let mut step = from;
+        for level in (0..self.jumps.len()).rev() {
+            let next_step = step
+                .checked_add(1 << level)
+                .filter(|&next_step| next_step <= self.max_steps());
+            if let (false, Some(next_step)) = (self.hits[level][state], next_step) {
+                state = self.jumps[level][state] as usize;
+                step = next_step;
+            }
+        }
+        self.hits[0][state].then_some(step)
+    }
+}
+
+/// The nodes that walks start or end at in [`Day8::walks`], [`Day8::first_common_goal_step`] and the ghost reports.
+pub enum NodeSelector {
+    /// The node with exactly this id.
+    Id(String),
+    /// Nodes whose ids start with this.
+    Prefix(String),
+    /// Nodes whose ids end with this.
+    Suffix(String),
+    /// Nodes whose whole ids match this pattern.
+    Pattern(Pattern),
+}
+
+impl NodeSelector {
+    pub fn matches(&self, id: &str) -> bool {
+        match self {
+            NodeSelector::Id(expected) => id == expected,
+            NodeSelector::Prefix(prefix) => id.starts_with(prefix.as_str()),
+            NodeSelector::Suffix(suffix) => id.ends_with(suffix.as_str()),
+            NodeSelector::Pattern(pattern) => pattern.is_match(id),
+        }
+    }
+}
+
+/// Parses `prefix:<prefix>`, `suffix:<suffix>` or `re:<pattern>`, or otherwise an exact id.
+impl FromStr for NodeSelector {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(prefix) = s.strip_prefix("prefix:") {
+            Ok(NodeSelector::Prefix(prefix.to_owned()))
+        } else if let Some(suffix) = s.strip_prefix("suffix:") {
+            Ok(NodeSelector::Suffix(suffix.to_owned()))
+        } else if let Some(pattern) = s.strip_prefix("re:") {
+            Ok(NodeSelector::Pattern(pattern.parse()?))
+        } else {
+            Ok(NodeSelector::Id(s.to_owned()))
+        }
+    }
+}
+
+/// Where a walk from a start node first reaches a goal node, as returned by [`Day8::walks`].
+pub struct Walk {
+    pub start: String,
+    /// The number of steps to the first goal node along with its id, or `None` if the walk never reaches one.
+    pub goal: Option<(u32, String)>,
+}
+
+/// Fill colours of the A and Z nodes in [`Day8::dot`].
+const DOT_START_COLOR: &str = "palegreen";
+const DOT_END_COLOR: &str = "lightcoral";
+/// Colours of the ghosts' cycles in [`Day8::dot`], which are reused if there are more ghosts than colours.
+const DOT_CYCLE_COLORS: [&str; 8] = ["red", "blue", "darkgreen", "orange", "purple", "brown", "magenta", "cyan4"];
+
+/// Returns `id` as a quoted DOT identifier.
+fn dot_id(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Where a ghost is after jumping ahead, as returned by [`Day8::jump`].
+pub struct GhostJump {
+    pub start: String,
+    pub node: String,
+    pub next_z_step: NextZStep,
+}
+
+/// When a ghost is next on a Z node, from the step it jumped to onward.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum NextZStep {
+    At(u64),
+    /// The ghost is on a Z node again, but only after more than `u64::MAX` steps.
+    BeyondRange,
+    Never,
+}
+
+/// When walks are first all on goals at the same time, as returned by [`Day8::first_common_goal_step`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CommonGoalStep {
+    At(u64),
+    /// The walks are all on goals at the same time, but only after more than `u64::MAX` steps.
+    BeyondRange,
+    Never,
+    /// The search gave up before finding a common step or ruling one out, or found none up to `u64::MAX` steps without
+    /// knowing whether there's one after.
+    Undetermined,
+}
+
+/// The shape of every ghost's path, as returned by [`Day8::analyze`].
+pub struct CycleReport {
+    /// The length of the L/R sequence.
+    pub dir_count: u64,
+    /// The prime factors of `dir_count` along with their exponents, in ascending order.
+    pub dir_count_factors: Vec<(u64, u32)>,
+    pub ghosts: Vec<GhostReport>,
+}
+
+/// The cycle that a ghost ends up in, as part of a [`CycleReport`].
+pub struct GhostReport {
+    pub start: String,
+    /// The number of steps before the ghost's path starts repeating.
+    pub lead_in: usize,
+    pub cycle_length: usize,
+    /// The steps within the first round of the cycle at which the ghost is on a Z node, along with the Z node's id. The
+    /// ghost is on the same Z node again every `cycle_length` steps.
+    pub z_nodes: Vec<(usize, String)>,
+    /// The cycle length divided by the L/R sequence length, which always divides it.
+    pub dir_multiple: u64,
+    /// The prime factors of `dir_multiple` along with their exponents, in ascending order.
+    pub dir_multiple_factors: Vec<(u64, u32)>,
+}
+
+/// The path of a ghost from its start node, which eventually repeats since there's a finite number of walker states.
+struct GhostCycle {
+    cycle: Cycle,
+    /// Steps before the first repeated state at which the ghost is on a Z node, in ascending order. The ones from the
+    /// cycle's lead-in onward repeat every cycle length steps.
+    z_steps: Vec<usize>,
+}
+
+impl GhostCycle {
+    fn measure<P: Fn(usize) -> bool>(walker: &GraphWalker, is_z: P) -> GhostCycle {
+        let (cycle, states) = cycle::with_memo(walker.state(), |&state| walker.next_state(state));
+        let z_steps = states
+            .iter()
+            .enumerate()
+            .filter_map(|(step, &(node, _))| is_z(node).then_some(step))
+            .collect();
+        GhostCycle { cycle, z_steps }
+    }
+
+    /// Returns whether the ghost is on a Z node after `step` steps.
+    fn is_z_step(&self, step: u64) -> bool {
+        self.z_steps.binary_search(&self.cycle.reduce(step)).is_ok()
+    }
+
+    /// Returns the Z steps within the cycle modulo the cycle length, in ascending order. From the lead-in onward, the ghost is
+    /// on a Z node exactly at the steps that are congruent to one of them.
+    fn cycle_z_residues(&self) -> Vec<u64> {
+        let mut residues: Vec<u64> = self
+            .z_steps
+            .iter()
+            .filter(|&&step| step >= self.cycle.lead_in)
+            .map(|&step| (step % self.cycle.length) as u64)
+            .collect();
+        residues.sort_unstable();
+        residues
+    }
+}
+
+/// The most congruences [`first_common_z_step`] combines the ghosts' Z steps into before checking steps one by one.
+const MAX_CONGRUENCES: usize = 10_000;
+
+/// The most steps [`first_common_z_step`] checks one by one before giving up.
+const MAX_SCANNED_STEPS: u64 = 10_000_000;
+
+/// Removes every Z residue of a ghost, given along with its cycle length, that no Z residue of some other ghost is congruent
+/// to modulo the GCD of their cycle lengths, since no common Z step can be congruent to it. Repeats until nothing changes,
+/// and returns `false` as soon as a ghost is left without any.
+fn prune_z_residues(ghosts: &mut [(u64, Vec<u64>)]) -> bool {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for ghost in 0..ghosts.len() {
+            for other in 0..ghosts.len() {
+                let gcd = ghosts[ghost].0.gcd(ghosts[other].0);
+                if ghost == other || gcd == 1 {
+                    continue;
+                }
+                let mut agreeing = vec![false; gcd as usize];
+                for &residue in ghosts[other].1.iter() {
+                    agreeing[(residue % gcd) as usize] = true;
+                }
+                let residues = &mut ghosts[ghost].1;
+                let count = residues.len();
+                residues.retain(|&residue| agreeing[(residue % gcd) as usize]);
+                if residues.is_empty() {
+                    return false;
+                }
+                changed |= residues.len() < count;
+            }
+        }
+    }
+    true
+}
+
+/// Returns the first step at which every ghost is on a Z node at the same time.
+fn first_common_z_step(ghosts: &[GhostCycle]) -> CommonGoalStep {
+    // Until every ghost has entered its cycle, there are no more steps than there are states, so they're checked one by one.
+    let Some(lead_in) = ghosts.iter().map(|ghost| ghost.cycle.lead_in as u64).max() else {
+        return CommonGoalStep::Never;
+    };
+    if let Some(step) = (0..lead_in).find(|&step| ghosts.iter().all(|ghost| ghost.is_z_step(step))) {
+        return CommonGoalStep::At(step);
+    }
+
+    // From then on, a step is a common Z step if it's congruent to one of the Z residues of every ghost's cycle. Residues
+    // that can't agree with any of another ghost's are ruled out first, which is often enough to tell there's no common step.
+    let mut ghosts: Vec<(u64, Vec<u64>)> = ghosts
+        .iter()
+        .map(|ghost| (ghost.cycle.length as u64, ghost.cycle_z_residues()))
+        .collect();
+    if !prune_z_residues(&mut ghosts) {
+        return CommonGoalStep::Never;
+    }
+
+    // Combining the congruences multiplies their numbers, so ghosts with the fewest Z residues are combined first, and only
+    // until there would be more than `MAX_CONGRUENCES` of them or their modulus would overflow.
+    ghosts.sort_by_key(|(_, residues)| residues.len());
+    let mut congruences = vec![(0, 1)];
+    let mut modulus: u64 = 1;
+    let mut combined = 0;
+    for (length, residues) in ghosts.iter() {
+        let Some(next_modulus) = modulus.checked_lcm(*length) else {
+            break;
+        };
+        if combined > 0 && congruences.len() * residues.len() > MAX_CONGRUENCES {
+            break;
+        }
+        // With the modulus known to fit, combining only fails for congruences without a common solution.
+        congruences = congruences
+            .iter()
+            .flat_map(|&congruence| {
+                residues
+                    .iter()
+                    .filter_map(move |&residue| u64::combine_congruences(congruence, (residue, *length)).ok())
+            })
+            .collect();
+        if congruences.is_empty() {
+            return CommonGoalStep::Never;
+        }
+        congruences.sort_unstable();
+        congruences.dedup();
+        modulus = next_modulus;
+        combined += 1;
+    }
+    let remaining = &ghosts[combined..];
+    if remaining.is_empty() {
+        // Every congruence has steps from the lead-in onward, but they might all be past `u64::MAX`.
+        return congruences
+            .into_iter()
+            .filter_map(|(r, m)| {
+                if r >= lead_in {
+                    Some(r)
+                } else {
+                    r.checked_add((lead_in - r).div_ceil(m).checked_mul(m)?)
+                }
+            })
+            .min()
+            .map_or(CommonGoalStep::BeyondRange, CommonGoalStep::At);
+    }
+
+    // The combined congruences all have the same modulus, so their steps are scanned in order and the remaining ghosts are
+    // checked one by one. Once every ghost's cycle has come round at the same time, the steps only repeat, so reaching that
+    // rules out a common step. Without reaching it, whether there is one is undetermined.
+    let end = checked_lcm_all(ghosts.iter().map(|(length, _)| *length)).and_then(|period| lead_in.checked_add(period));
+    let mut base = lead_in / modulus * modulus;
+    let mut scanned = 0;
+    loop {
+        for &(r, _) in congruences.iter() {
+            let Some(step) = base.checked_add(r) else {
+                return CommonGoalStep::Undetermined;
+            };
+            if end.is_some_and(|end| step >= end) {
+                return CommonGoalStep::Never;
+            }
+            if step < lead_in {
+                continue;
+            }
+            scanned += 1;
+            if scanned > MAX_SCANNED_STEPS {
+                return CommonGoalStep::Undetermined;
+            }
+            if remaining
+                .iter()
+                .all(|(length, residues)| residues.binary_search(&(step % length)).is_ok())
+            {
+                return CommonGoalStep::At(step);
+            }
+        }
+        let Some(next_base) = base.checked_add(modulus) else {
+            return CommonGoalStep::Undetermined;
+        };
+        base = next_base;
     }
 }
 
 impl Day8 {
-    #[allow(dead_code)]
-    fn examine_data(&self) {
-        // This function shows that every starting point (A) has one distinct ending point (Z) and a distinct loop length until
-        // it repeats its path along the graph. By making this assumption, part 2 becomes easier to solve.
+    fn nodes_matching<'a>(&'a self, selector: &'a NodeSelector) -> impl Iterator<Item = usize> + 'a {
+        (0..self.network.len()).filter(|&node| selector.matches(self.network.name(node)))
+    }
 
-        let graph_nodes = graph_nodes(&self.nodes);
+    /// Walks from every node matching `starts` until a node matching `goals` is reached, which includes the start node
+    /// itself. Walks that never reach a goal are detected by their paths repeating.
+    pub fn walks(&self, starts: &NodeSelector, goals: &NodeSelector) -> Vec<Walk> {
+        self.nodes_matching(starts)
+            .map(|start| {
+                let mut walker = GraphWalker::new(&self.network, &self.dirs, start);
+                let reached = walker.walk_to(|node| goals.matches(self.network.name(node)));
+                Walk {
+                    start: self.network.name(start).to_owned(),
+                    goal: reached.then(|| (walker.steps, self.network.name(walker.current).to_owned())),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the first step at which walkers from every node matching `starts` are all on nodes matching `goals`. That
+    /// never happens if no node matches `starts`.
+    pub fn first_common_goal_step(&self, starts: &NodeSelector, goals: &NodeSelector) -> CommonGoalStep {
+        let walkers: Vec<GhostCycle> = self
+            .nodes_matching(starts)
+            .map(|start| {
+                GhostCycle::measure(&GraphWalker::new(&self.network, &self.dirs, start), |node| {
+                    goals.matches(self.network.name(node))
+                })
+            })
+            .collect();
+        first_common_z_step(&walkers)
+    }
 
-        let starts: Vec<(usize, &GraphNode)> = graph_nodes.iter().enumerate().filter(|(_, node)| node.id[2] == 'A').collect();
+    /// Returns where a ghost starting at every node matching `starts` is after `steps` steps, using jump tables instead of
+    /// walking or relying on cycles, with the nodes matching `goals` as Z nodes. Only the steps up to `steps` plus the
+    /// number of walker states are searched for the next Z step, which is enough to find one if there is one. When that
+    /// search would go past `u64::MAX`, a ghost that isn't found on a Z node is only reported as never on one again if there
+    /// is no Z node in its cycle.
+    pub fn jump(&self, starts: &NodeSelector, goals: &NodeSelector, steps: u64) -> Vec<GhostJump> {
+        let state_count = (self.network.len() * self.dirs.len()) as u64;
+        let is_z_node = |node| goals.matches(self.network.name(node));
+        let table = JumpTable::new(&self.network, &self.dirs, steps.saturating_add(state_count), is_z_node);
+        self.nodes_matching(starts)
+            .map(|start| {
+                let state = (start, 0);
+                let (node, _) = table.jump(state, steps);
+                let next_z_step = match table.first_hit(state, steps) {
+                    Some(z_step) => NextZStep::At(z_step),
+                    None if self.cycle_states(start).iter().any(|&(node, _)| is_z_node(node)) => NextZStep::BeyondRange,
+                    None => NextZStep::Never,
+                };
+                GhostJump {
+                    start: self.network.name(start).to_owned(),
+                    node: self.network.name(node).to_owned(),
+                    next_z_step,
+                }
+            })
+            .collect()
+    }
 
-        for (index, start) in starts {
-            let mut memo: HashSet<(usize, usize)> = HashSet::new();
-            let mut zs: HashSet<Id> = HashSet::new();
-            let mut walker = GraphWalker::new(&graph_nodes, &self.dirs, index);
+    /// Returns the states that a ghost starting at `start` keeps repeating once it has entered its cycle, in the order it
+    /// visits them.
+    fn cycle_states(&self, start: usize) -> Vec<WalkerState> {
+        let walker = GraphWalker::new(&self.network, &self.dirs, start);
+        let (cycle, mut states) = cycle::with_memo(walker.state(), |&state| walker.next_state(state));
+        states.split_off(cycle.lead_in)
+    }
 
-            while !memo.contains(&(walker.current, walker.dir_index)) {
-                memo.insert((walker.current, walker.dir_index));
-                if walker.current_node().id[2] == 'Z' {
-                    zs.insert(walker.current_node().id);
+    /// Returns the network in Graphviz DOT format, with the nodes matching `starts` and `goals` filled in and every edge
+    /// labelled with its direction. If `color_cycles` is set, the edges on the cycle of each ghost starting at a node
+    /// matching `starts` are coloured, so that edges shared by several cycles get several colours.
+    pub fn dot(&self, starts: &NodeSelector, goals: &NodeSelector, color_cycles: bool) -> String {
+        // The colours of every edge, by node and position among the node's edges.
+        let mut edge_colors: Vec<Vec<Vec<&str>>> = (0..self.network.len())
+            .map(|node| vec![vec![]; self.network.edges(node).len()])
+            .collect();
+        let mut lines = vec!["digraph day8 {".to_owned()];
+        if color_cycles {
+            for (ghost, start) in self.nodes_matching(starts).enumerate() {
+                let color = DOT_CYCLE_COLORS[ghost % DOT_CYCLE_COLORS.len()];
+                let states = self.cycle_states(start);
+                lines.push(format!(
+                    "    // {}: cycle of {} steps in {color}",
+                    self.network.name(start),
+                    states.len()
+                ));
+                for state in states {
+                    let colors = &mut edge_colors[state.0][next_edge(&self.network, &self.dirs, state)];
+                    if !colors.contains(&color) {
+                        colors.push(color);
+                    }
                 }
-                walker.walk_once();
             }
-            println!(
-                "Starting at {:?} loops after {} steps and has {} Zs: {:?}",
-                start.id,
-                walker.steps,
-                zs.len(),
-                zs.iter().collect::<Vec<&Id>>()
-            );
+        }
+
+        lines.push("    node [shape=circle];".to_owned());
+        for (_, id) in self.network.nodes() {
+            if starts.matches(id) {
+                lines.push(format!("    {} [style=filled, fillcolor={DOT_START_COLOR}];", dot_id(id)));
+            } else if goals.matches(id) {
+                lines.push(format!("    {} [style=filled, fillcolor={DOT_END_COLOR}];", dot_id(id)));
+            }
+        }
+        for (node, id) in self.network.nodes() {
+            for (edge, colors) in self.network.edges(node).iter().zip(&edge_colors[node]) {
+                let label = match edge.label {
+                    Dir::Left => 'L',
+                    Dir::Right => 'R',
+                };
+                let style = if colors.is_empty() {
+                    String::new()
+                } else {
+                    format!(", color=\"{}\", penwidth=2", colors.join(":"))
+                };
+                lines.push(format!(
+                    "    {} -> {} [label={label}{style}];",
+                    dot_id(id),
+                    dot_id(self.network.name(edge.to))
+                ));
+            }
+        }
+        lines.push("}".to_owned());
+        lines.join("\n")
+    }
+
+    /// Measures the path of a ghost starting at every node matching `starts`, reporting its cycle and the nodes matching
+    /// `goals` on it as Z nodes.
+    pub fn analyze(&self, starts: &NodeSelector, goals: &NodeSelector) -> CycleReport {
+        let dir_count = self.dirs.len() as u64;
+        let ghosts = self
+            .nodes_matching(starts)
+            .map(|start| {
+                let walker = GraphWalker::new(&self.network, &self.dirs, start);
+                let (cycle, states) = cycle::with_memo(walker.state(), |&state| walker.next_state(state));
+                let z_nodes = states
+                    .iter()
+                    .enumerate()
+                    .skip(cycle.lead_in)
+                    .filter(|&(_, &(node, _))| goals.matches(self.network.name(node)))
+                    .map(|(step, &(node, _))| (step, self.network.name(node).to_owned()))
+                    .collect();
+                // Every state includes the index of the next instruction, so cycles always span whole L/R sequences.
+                let dir_multiple = cycle.length as u64 / dir_count;
+                GhostReport {
+                    start: self.network.name(start).to_owned(),
+                    lead_in: cycle.lead_in,
+                    cycle_length: cycle.length,
+                    z_nodes,
+                    dir_multiple,
+                    dir_multiple_factors: dir_multiple.prime_factors(),
+                }
+            })
+            .collect();
+        CycleReport {
+            dir_count,
+            dir_count_factors: dir_count.prime_factors(),
+            ghosts,
         }
     }
 }
@@ -129,16 +619,17 @@ impl Solver for Day8 {
     fn new() -> Self {
         Day8 {
             dirs: vec![],
-            nodes: vec![],
+            network: Graph::new(),
         }
     }
 
     fn reset(&mut self) {
-        self.nodes.clear();
+        self.dirs.clear();
+        self.network = Graph::new();
     }
 
-    fn parse_input(&mut self) {
-        let (dirs, nodes) = INPUT.split_once("\n\n").unwrap();
+    fn parse_input(&mut self, input: &str) {
+        let (dirs, nodes) = input.split_once("\n\n").unwrap();
         self.dirs = dirs
             .chars()
             .map(|ch| match ch {
@@ -147,64 +638,38 @@ impl Solver for Day8 {
                 _ => panic!(),
             })
             .collect();
-        self.nodes = nodes
-            .lines()
-            .map(|line| {
-                let (id, children) = line.split_once('=').unwrap();
-                let id = id.trim().as_array();
-                let children = children.trim();
-                let children = &children[1..children.len() - 1];
-                let (left, right) = children.split_once(", ").unwrap();
-                let left = left.as_array();
-                let right = right.as_array();
-                Node { id, left, right }
-            })
-            .collect();
+        for line in nodes.lines() {
+            let (id, children) = line.split_once('=').unwrap();
+            let children = children.trim();
+            let children = &children[1..children.len() - 1];
+            let (left, right) = children.split_once(", ").unwrap();
+            let [id, left, right] = [id.trim(), left, right].map(|id| self.network.add_node(id));
+            self.network.add_edge(id, left, 1, Dir::Left);
+            self.network.add_edge(id, right, 1, Dir::Right);
+        }
+        // Nodes can be referenced before they're defined, so they're only known to exist once every line has been parsed.
+        if let Some((_, id)) = self.network.nodes().find(|&(node, _)| self.network.edges(node).is_empty()) {
+            panic!("node {id} should be defined");
+        }
     }
 
     fn solve_part1(&self) -> u32 {
-        let graph_nodes = graph_nodes(&self.nodes);
-
-        let aaa = node_index_by_id(&self.nodes, &['A', 'A', 'A']).unwrap();
-        let zzz = node_index_by_id(&self.nodes, &['Z', 'Z', 'Z']).unwrap();
-
-        let mut walker = GraphWalker::new(&graph_nodes, &self.dirs, aaa);
-        while walker.current != zzz {
-            walker.walk_once();
-        }
-
-        walker.steps
+        let walks = self.walks(&NodeSelector::Id("AAA".to_owned()), &NodeSelector::Id("ZZZ".to_owned()));
+        let (steps, _) = walks
+            .into_iter()
+            .next()
+            .and_then(|walk| walk.goal)
+            .expect("ZZZ should be reachable from AAA");
+        steps
     }
 
     fn solve_part2(&self) -> u64 {
-        let graph_nodes = graph_nodes(&self.nodes);
-
-        graph_nodes
-            .iter()
-            .enumerate()
-            .filter_map(|(index, node)| (node.id[2] == 'A').then_some(index))
-            .map(|start| {
-                let mut memo: HashSet<(usize, usize)> = HashSet::new();
-                let mut z = None;
-                let mut walker = GraphWalker::new(&graph_nodes, &self.dirs, start);
-
-                while !memo.contains(&(walker.current, walker.dir_index)) {
-                    memo.insert((walker.current, walker.dir_index));
-                    if walker.current_node().id[2] == 'Z' {
-                        z = Some(walker.steps);
-                    }
-                    walker.walk_once();
-                }
-
-                let z = z.unwrap();
-                let loop_cycles = walker.steps as usize / self.dirs.len();
-                if z as usize != loop_cycles * self.dirs.len() {
-                    panic!();
-                }
-                loop_cycles as u64
-            })
-            .product::<u64>()
-            * self.dirs.len() as u64
+        let starts = NodeSelector::Suffix("A".to_owned());
+        let goals = NodeSelector::Suffix("Z".to_owned());
+        match self.first_common_goal_step(&starts, &goals) {
+            CommonGoalStep::At(steps) => steps,
+            _ => panic!("the ghosts should reach Z nodes at the same time"),
+        }
     }
 
     fn print_solutions(&self, part1: u32, part2: u64) {
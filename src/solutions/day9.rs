@@ -14,8 +14,6 @@ struct Pascal {
     rows: Vec<Vec<Value>>,
 }
 
-const INPUT: &str = include_str!("../../input/day9");
-
 impl Pascal {
     fn new() -> Pascal {
         Pascal { rows: vec![vec![1]] }
@@ -133,8 +131,8 @@ impl Solver for Day9 {
         self.histories.clear();
     }
 
-    fn parse_input(&mut self) {
-        self.histories = INPUT
+    fn parse_input(&mut self, input: &str) {
+        self.histories = input
             .lines()
             .map(|line| {
                 let values = line
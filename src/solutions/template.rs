@@ -4,14 +4,12 @@ use super::Solver;
 pub struct Day1 {
 }
 
-const INPUT: &str = include_str!("../../input/day1");
-
 impl Day1 {
 }
 
 impl Solver for Day1 {
     type Solution1 = u32;
-    type Solution2 = ();
+    type Solution2 = u32;
 
     fn new() -> Self {
     }
@@ -19,8 +17,8 @@ impl Solver for Day1 {
     fn reset(&mut self) {
     }
 
-    fn parse_input(&mut self) {
-        for line in INPUT.lines() {
+    fn parse_input(&mut self, input: &str) {
+        for line in input.lines() {
 
         }
     }
@@ -29,11 +27,11 @@ impl Solver for Day1 {
         0
     }
 
-    fn solve_part2(&self) {
-        
+    fn solve_part2(&self) -> u32 {
+        0
     }
 
-    fn print_solutions(&self, part1: u32, part2: ()) {
+    fn print_solutions(&self, part1: u32, part2: u32) {
         println!("Sum of calibration values: {part1}");
         // println!("Sum of 3 most calories carried: {part2}");
     }
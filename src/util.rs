@@ -1,8 +0,0 @@
-pub mod array;
-pub mod interleave;
-pub mod intset;
-pub mod number;
-pub mod pos;
-pub mod set;
-pub mod top;
-pub mod try_index;
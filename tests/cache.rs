@@ -0,0 +1,45 @@
+use std::{fs, path::PathBuf, process};
+
+use aoc2023::cache::Cache;
+
+/// Returns a cache directory under the system temp directory that doesn't exist yet.
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc2023-cache-{name}-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn get_after_insert() {
+    let dir = cache_dir("insert");
+    let cache = Cache::new(&dir);
+    assert_eq!(cache.get(1, "Day1", "1abc2"), None);
+    cache.insert(1, "Day1", "1abc2", ["142", "281"]).unwrap();
+    assert_eq!(cache.get(1, "Day1", "1abc2"), Some(["142".to_owned(), "281".to_owned()]));
+    assert_eq!(cache.get(1, "Day1", "pqr3stu8vwx"), None);
+    assert_eq!(cache.get(1, "Day1Fast", "1abc2"), None);
+    assert_eq!(cache.get(2, "Day1", "1abc2"), None);
+    cache.insert(1, "Day1", "1abc2", ["0", ""]).unwrap();
+    assert_eq!(cache.get(1, "Day1", "1abc2"), Some(["0".to_owned(), String::new()]));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn clear_days() {
+    let dir = cache_dir("clear");
+    let cache = Cache::new(&dir);
+    assert_eq!(cache.clear(None).unwrap(), 0);
+    cache.insert(1, "Day1", "a", ["1", "2"]).unwrap();
+    cache.insert(1, "Day1", "b", ["3", "4"]).unwrap();
+    cache.insert(11, "Day11", "a", ["5", "6"]).unwrap();
+    cache.insert(2, "Day2", "a", ["7", "8"]).unwrap();
+    fs::write(dir.join("notes"), "not an entry").unwrap();
+    assert_eq!(cache.clear(Some(1)).unwrap(), 2);
+    assert_eq!(cache.get(1, "Day1", "a"), None);
+    assert_eq!(cache.get(11, "Day11", "a"), Some(["5".to_owned(), "6".to_owned()]));
+    assert_eq!(cache.clear(Some(3)).unwrap(), 0);
+    assert_eq!(cache.clear(None).unwrap(), 2);
+    assert_eq!(cache.get(2, "Day2", "a"), None);
+    assert!(dir.join("notes").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
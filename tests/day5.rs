@@ -0,0 +1,334 @@
+#[path = "../util/tests/common/mod.rs"]
+mod common;
+
+use aoc2023::{
+    solutions::{
+        day5::{CategoryError, Day5, TraceStep},
+        Solver,
+    },
+    util::interval::IntervalSet,
+};
+use common::{Rng, CASES};
+
+const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// A map of an [`Almanac`], with its range rules as `[destination start, source start, length]`.
+struct Map {
+    source: &'static str,
+    destination: &'static str,
+    rules: Vec<[i64; 3]>,
+}
+
+/// An almanac generated independently of [`Day5`], which converts values one map at a time.
+struct Almanac {
+    seeds: Vec<i64>,
+    /// The maps in the order they convert seeds into locations.
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    /// Returns an almanac converting seeds into locations through a random selection of the other categories, with every
+    /// rule inside `0..52` so that values outside of it are never converted.
+    fn random(rng: &mut Rng) -> Almanac {
+        let mut categories = vec![CATEGORIES[0]];
+        categories.extend(CATEGORIES[1..7].iter().filter(|_| rng.range(0, 2) == 0));
+        categories.push(CATEGORIES[7]);
+        let maps = categories
+            .windows(2)
+            .map(|pair| Map {
+                source: pair[0],
+                destination: pair[1],
+                rules: (0..rng.range(1, 5))
+                    .map(|_| [rng.range(0, 40), rng.range(0, 40), rng.range(1, 13)])
+                    .collect(),
+            })
+            .collect();
+        let seeds = (0..rng.range(1, 4))
+            .flat_map(|_| [rng.range(0, 45), rng.range(1, 8)])
+            .collect();
+        Almanac { seeds, maps }
+    }
+
+    /// Returns the almanac as puzzle input, with the maps in the order given by `order`.
+    fn text(&self, order: impl IntoIterator<Item = usize>) -> String {
+        let seeds: Vec<String> = self.seeds.iter().map(i64::to_string).collect();
+        let mut text = format!("seeds: {}\n", seeds.join(" "));
+        for map in order.into_iter().map(|index| &self.maps[index]) {
+            text += &format!("\n{}-to-{} map:\n", map.source, map.destination);
+            for [dst_start, src_start, length] in &map.rules {
+                text += &format!("{dst_start} {src_start} {length}\n");
+            }
+        }
+        text
+    }
+
+    fn parse(&self) -> Day5 {
+        let mut day5 = Day5::new();
+        day5.parse_input(&self.text(0..self.maps.len()));
+        day5
+    }
+
+    /// Returns the index of the first rule of `map` that applies to `value`, and the value it's converted into.
+    fn convert(map: &Map, value: i64) -> (Option<usize>, i64) {
+        match map
+            .rules
+            .iter()
+            .position(|&[_, src, length]| (src..src + length).contains(&value))
+        {
+            Some(rule) => (Some(rule), value + map.rules[rule][0] - map.rules[rule][1]),
+            None => (None, value),
+        }
+    }
+
+    fn location(&self, seed: i64) -> i64 {
+        self.maps.iter().fold(seed, |value, map| Almanac::convert(map, value).1)
+    }
+
+    /// Returns the category, value and rule of every step converting `value` with the maps at `maps`, like
+    /// [`Day5::trace`].
+    fn trace(&self, maps: std::ops::Range<usize>, mut value: i64) -> Vec<(String, i64, Option<String>)> {
+        let mut steps = vec![(self.maps[maps.start].source.to_owned(), value, None)];
+        for map in &self.maps[maps] {
+            let (rule, converted) = Almanac::convert(map, value);
+            value = converted;
+            let rule = rule.map(|rule| {
+                let [dst_start, src_start, length] = map.rules[rule];
+                format!("rule {} ({dst_start} {src_start} {length})", rule + 1)
+            });
+            steps.push((map.destination.to_owned(), value, rule));
+        }
+        steps
+    }
+}
+
+fn steps(trace: Vec<TraceStep>) -> Vec<(String, i64, Option<String>)> {
+    trace.into_iter().map(|step| (step.category, step.value, step.rule)).collect()
+}
+
+#[test]
+fn composed_map_matches_converting_one_map_at_a_time() {
+    let mut rng = Rng::new(5);
+    for _ in 0..CASES {
+        let mut almanac = Almanac::random(&mut rng);
+        let day5 = almanac.parse();
+        let lowest = almanac.seeds.iter().map(|&seed| almanac.location(seed)).min().unwrap();
+        assert_eq!(day5.solve_part1(), lowest);
+        let lowest = almanac
+            .seeds
+            .chunks(2)
+            .flat_map(|range| range[0]..range[0] + range[1])
+            .map(|seed| almanac.location(seed))
+            .min()
+            .unwrap();
+        assert_eq!(day5.solve_part2(), lowest);
+
+        // Part 1 converts every listed seed, and part 2 converts the single seed of the range `seed..seed + 1`.
+        for _ in 0..10 {
+            let seed = rng.range(-5, 60);
+            almanac.seeds = vec![seed];
+            assert_eq!(almanac.parse().solve_part1(), almanac.location(seed), "seed {seed}");
+            almanac.seeds.push(1);
+            assert_eq!(almanac.parse().solve_part2(), almanac.location(seed), "seed {seed}");
+        }
+    }
+}
+
+#[test]
+fn seeds_reaching_matches_converting_every_seed() {
+    let mut rng = Rng::new(7);
+    for _ in 0..CASES {
+        let almanac = Almanac::random(&mut rng);
+        let day5 = almanac.parse();
+        let start = rng.range(-5, 55);
+        let locations = start..start + rng.range(0, 15);
+        // Seeds outside of `0..52` are never converted, so only those in between can reach `locations`.
+        let expected: IntervalSet<i64> = (-5..70)
+            .filter(|&seed| locations.contains(&almanac.location(seed)))
+            .map(|seed| seed..seed + 1)
+            .collect();
+        assert_eq!(day5.seeds_reaching(locations).unwrap(), expected);
+    }
+}
+
+#[test]
+fn trace_example() {
+    let mut day5 = Day5::new();
+    day5.parse_input(EXAMPLE);
+    let step = |category: &str, value, rule: Option<&str>| (category.to_owned(), value, rule.map(str::to_owned));
+    assert_eq!(
+        steps(day5.trace("seed", "location", 79).unwrap()),
+        [
+            step("seed", 79, None),
+            step("soil", 81, Some("rule 2 (52 50 48)")),
+            step("fertilizer", 81, None),
+            step("water", 81, None),
+            step("light", 74, Some("rule 2 (18 25 70)")),
+            step("temperature", 78, Some("rule 3 (68 64 13)")),
+            step("humidity", 78, None),
+            step("location", 82, Some("rule 1 (60 56 37)")),
+        ]
+    );
+}
+
+#[test]
+fn trace_names_the_rule_of_each_step() {
+    let mut rng = Rng::new(11);
+    for _ in 0..CASES {
+        let almanac = Almanac::random(&mut rng);
+        let day5 = almanac.parse();
+        let seed = rng.range(-5, 60);
+        assert_eq!(
+            steps(day5.trace("seed", "location", seed).unwrap()),
+            almanac.trace(0..almanac.maps.len(), seed)
+        );
+    }
+}
+
+#[test]
+fn maps_listed_out_of_order() {
+    let mut rng = Rng::new(13);
+    for _ in 0..CASES {
+        let almanac = Almanac::random(&mut rng);
+        let mut order: Vec<usize> = (0..almanac.maps.len()).collect();
+        for i in (1..order.len()).rev() {
+            order.swap(i, rng.range(0, i as i64 + 1) as usize);
+        }
+        let mut day5 = Day5::new();
+        day5.parse_input(&almanac.text(order));
+        let in_order = almanac.parse();
+        assert_eq!(day5.solve_part1(), in_order.solve_part1());
+        assert_eq!(day5.solve_part2(), in_order.solve_part2());
+        let seed = rng.range(-5, 60);
+        assert_eq!(
+            steps(day5.trace("seed", "location", seed).unwrap()),
+            almanac.trace(0..almanac.maps.len(), seed)
+        );
+    }
+}
+
+#[test]
+fn trace_between_any_categories() {
+    let mut day5 = Day5::new();
+    day5.parse_input(EXAMPLE);
+    let trace = steps(day5.trace("soil", "humidity", 81).unwrap());
+    let categories: Vec<&str> = trace.iter().map(|(category, _, _)| category.as_str()).collect();
+    assert_eq!(
+        categories,
+        ["soil", "fertilizer", "water", "light", "temperature", "humidity"]
+    );
+    assert_eq!(trace.last().unwrap().1, 78);
+    assert_eq!(
+        steps(day5.trace("light", "light", 74).unwrap()),
+        [("light".to_owned(), 74, None)]
+    );
+
+    let mut rng = Rng::new(17);
+    for _ in 0..CASES {
+        let almanac = Almanac::random(&mut rng);
+        let day5 = almanac.parse();
+        let from = rng.range(0, almanac.maps.len() as i64) as usize;
+        let to = rng.range(from as i64 + 1, almanac.maps.len() as i64 + 1) as usize;
+        let value = rng.range(-5, 60);
+        assert_eq!(
+            steps(
+                day5.trace(almanac.maps[from].source, almanac.maps[to - 1].destination, value)
+                    .unwrap()
+            ),
+            almanac.trace(from..to, value)
+        );
+    }
+}
+
+#[test]
+fn category_errors() {
+    let mut day5 = Day5::new();
+    day5.parse_input(EXAMPLE);
+    assert_eq!(
+        day5.trace("seed", "fish", 79).err(),
+        Some(CategoryError::Unknown("fish".to_owned()))
+    );
+    assert_eq!(
+        day5.trace("fish", "location", 79).err(),
+        Some(CategoryError::Unknown("fish".to_owned()))
+    );
+    assert_eq!(
+        day5.trace("location", "seed", 82).err(),
+        Some(CategoryError::Missing {
+            from: "location".to_owned(),
+            to: "seed".to_owned()
+        })
+    );
+
+    // Without the water-to-light map, nothing converts seeds into locations.
+    let (before, after) = EXAMPLE.split_once("water-to-light").unwrap();
+    let (_, after) = after.split_once("\n\n").unwrap();
+    day5.reset();
+    day5.parse_input(&format!("{before}{after}"));
+    let missing = CategoryError::Missing {
+        from: "seed".to_owned(),
+        to: "location".to_owned(),
+    };
+    assert_eq!(day5.trace("seed", "location", 79).err(), Some(missing.clone()));
+    assert_eq!(day5.seeds_reaching(0..100).err(), Some(missing));
+    assert!(day5.trace("seed", "water", 79).is_ok());
+
+    // A map from seeds straight into water adds a second way to convert seeds into locations.
+    day5.reset();
+    day5.parse_input(&format!("{EXAMPLE}\nseed-to-water map:\n0 0 1\n"));
+    let ambiguous = CategoryError::Ambiguous {
+        from: "seed".to_owned(),
+        to: "location".to_owned(),
+    };
+    assert_eq!(day5.trace("seed", "location", 79).err(), Some(ambiguous.clone()));
+    assert_eq!(day5.seeds_reaching(0..100).err(), Some(ambiguous));
+    assert!(day5.trace("water", "location", 81).is_ok());
+
+    // Maps leading back to an earlier category don't make the search go around in circles.
+    day5.reset();
+    day5.parse_input(&format!("{EXAMPLE}\nlocation-to-soil map:\n0 0 1\n"));
+    assert_eq!(day5.trace("seed", "location", 79).unwrap().len(), 8);
+}
@@ -0,0 +1,309 @@
+#[path = "../util/tests/common/mod.rs"]
+mod common;
+
+use std::collections::{HashMap, HashSet};
+
+use aoc2023::solutions::{
+    day8::{CommonGoalStep, Day8, NextZStep, NodeSelector},
+    Solver,
+};
+use common::{Rng, CASES};
+
+/// A network parsed independently of [`Day8`], which walks one step at a time.
+struct Network {
+    dirs: Vec<char>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    fn parse(input: &str) -> Network {
+        let (dirs, nodes) = input.split_once("\n\n").unwrap();
+        let nodes = nodes
+            .lines()
+            .map(|line| {
+                let (id, children) = line.split_once(" = (").unwrap();
+                let (left, right) = children.trim_end_matches(')').split_once(", ").unwrap();
+                (id.to_owned(), (left.to_owned(), right.to_owned()))
+            })
+            .collect();
+        Network {
+            dirs: dirs.chars().collect(),
+            nodes,
+        }
+    }
+
+    /// Returns the start of the ring `ring` in [`goal_rings`].
+    fn start(&self, ring: usize) -> &str {
+        self.nodes.keys().find(|id| id.starts_with(&format!("G{ring}S"))).unwrap()
+    }
+
+    fn walk<'a>(&'a self, start: &'a str) -> impl Iterator<Item = &'a str> {
+        let mut current = start;
+        let mut dirs = self.dirs.iter().cycle();
+        std::iter::once(start).chain(std::iter::from_fn(move || {
+            let (left, right) = &self.nodes[current];
+            current = if *dirs.next().unwrap() == 'L' { left } else { right };
+            Some(current)
+        }))
+    }
+}
+
+impl Network {
+    /// Walks from every A node at once until they're all on Z nodes, returning the step that happens at or `None` if their
+    /// combined state repeats first or there are no A nodes. Gives up with `Err(())` after `limit` steps.
+    fn first_common_z_step(&self, limit: u64) -> Result<Option<u64>, ()> {
+        let ids: Vec<&String> = self.nodes.keys().collect();
+        let index: HashMap<&String, usize> = ids.iter().enumerate().map(|(node, &id)| (id, node)).collect();
+        let next: Vec<[usize; 2]> = ids
+            .iter()
+            .map(|&id| {
+                let (left, right) = &self.nodes[id];
+                [index[left], index[right]]
+            })
+            .collect();
+        let mut ghosts: Vec<usize> = (0..ids.len()).filter(|&node| ids[node].ends_with('A')).collect();
+        if ghosts.is_empty() {
+            return Ok(None);
+        }
+        let mut seen = HashSet::new();
+        for step in 0..limit {
+            if ghosts.iter().all(|&node| ids[node].ends_with('Z')) {
+                return Ok(Some(step));
+            }
+            let dir_index = step as usize % self.dirs.len();
+            if !seen.insert((ghosts.clone(), dir_index)) {
+                return Ok(None);
+            }
+            let side = if self.dirs[dir_index] == 'L' { 0 } else { 1 };
+            ghosts.iter_mut().for_each(|node| *node = next[*node][side]);
+        }
+        Err(())
+    }
+}
+
+/// Returns selectors for the ghosts of part 2, which go from the A nodes to the Z nodes.
+fn ghost_selectors() -> [NodeSelector; 2] {
+    [NodeSelector::Suffix("A".to_owned()), NodeSelector::Suffix("Z".to_owned())]
+}
+
+fn random_network(rng: &mut Rng) -> String {
+    let node_count = rng.range(1, 12) as usize;
+    let names: Vec<String> = (0..node_count)
+        .map(|node| format!("N{node}{}", ['A', 'B', 'Z'][rng.range(0, 3) as usize]))
+        .collect();
+    let dirs: String = (0..rng.range(1, 5))
+        .map(|_| if rng.range(0, 2) == 0 { 'L' } else { 'R' })
+        .collect();
+    let nodes: Vec<String> = names
+        .iter()
+        .map(|name| {
+            let [left, right] = [0, 1].map(|_| &names[rng.range(0, node_count as i64) as usize]);
+            format!("{name} = ({left}, {right})")
+        })
+        .collect();
+    format!("{dirs}\n\n{}", nodes.join("\n"))
+}
+
+#[test]
+fn jump_matches_walking() {
+    let mut rng = Rng::new(8);
+    let [starts, goals] = ghost_selectors();
+    for _ in 0..CASES {
+        let input = random_network(&mut rng);
+        let network = Network::parse(&input);
+        let mut day8 = Day8::new();
+        day8.parse_input(&input);
+        let state_count = network.nodes.len() * network.dirs.len();
+        let steps = rng.range(0, 3 * state_count as i64) as u64;
+        let mut start_ids: Vec<&str> = network
+            .nodes
+            .keys()
+            .map(String::as_str)
+            .filter(|id| id.ends_with('A'))
+            .collect();
+        start_ids.sort();
+
+        let ghosts = day8.jump(&starts, &goals, steps);
+        let mut ghost_starts: Vec<&str> = ghosts.iter().map(|ghost| ghost.start.as_str()).collect();
+        ghost_starts.sort();
+        assert_eq!(ghost_starts, start_ids, "{input}");
+        for ghost in ghosts.iter() {
+            let mut walk = network
+                .walk(&ghost.start)
+                .skip(steps as usize)
+                .take(state_count + 1)
+                .peekable();
+            let expected_node = *walk.peek().unwrap();
+            let expected_z_step = match walk.position(|node| node.ends_with('Z')) {
+                Some(offset) => NextZStep::At(steps + offset as u64),
+                None => NextZStep::Never,
+            };
+            assert_eq!(ghost.node, expected_node, "{input}\nafter {steps} steps");
+            assert_eq!(ghost.next_z_step, expected_z_step, "{input}\nafter {steps} steps");
+        }
+    }
+}
+
+#[test]
+fn jump_to_the_last_steps() {
+    let mut day8 = Day8::new();
+    day8.parse_input("L\n\nAAA = (BBB, BBB)\nBBB = (ZZZ, ZZZ)\nZZZ = (BBB, BBB)\nCCA = (CCA, CCA)");
+
+    let [starts, goals] = ghost_selectors();
+
+    let ghosts = day8.jump(&starts, &goals, u64::MAX - 1);
+    assert_eq!(ghosts[0].node, "ZZZ");
+    assert_eq!(ghosts[0].next_z_step, NextZStep::At(u64::MAX - 1));
+    assert_eq!(ghosts[1].next_z_step, NextZStep::Never);
+
+    let ghosts = day8.jump(&starts, &goals, u64::MAX);
+    assert_eq!(ghosts[0].node, "BBB");
+    assert_eq!(ghosts[0].next_z_step, NextZStep::BeyondRange);
+    assert_eq!(ghosts[1].node, "CCA");
+    assert_eq!(ghosts[1].next_z_step, NextZStep::Never);
+}
+
+/// Returns rings of the given lengths following the instructions `dirs`, naming every node by its ring and position.
+fn rings(dirs: &str, lengths: &[usize], mut name: impl FnMut(usize, usize) -> String) -> String {
+    let mut nodes = vec![];
+    for (ring, &length) in lengths.iter().enumerate() {
+        let names: Vec<String> = (0..length).map(|node| name(ring, node)).collect();
+        for (node, name) in names.iter().enumerate() {
+            let next = &names[(node + 1) % length];
+            nodes.push(format!("{name} = ({next}, {next})"));
+        }
+    }
+    format!("{dirs}\n\n{}", nodes.join("\n"))
+}
+
+/// Returns rings of the given lengths, which each start at `G<ring>S` and have the nodes that `is_goal` holds for end in `X`.
+fn goal_rings(lengths: &[usize], mut is_goal: impl FnMut(usize, usize) -> bool) -> String {
+    rings("LR", lengths, |ring, node| {
+        let goal = if is_goal(ring, node) { "X" } else { "" };
+        match node {
+            0 => format!("G{ring}S{goal}"),
+            _ => format!("R{ring}N{node}{goal}"),
+        }
+    })
+}
+
+#[test]
+fn common_goal_step_with_many_goals_per_cycle() {
+    let mut rng = Rng::new(50);
+    let starts: NodeSelector = "re:G.S.*".parse().unwrap();
+    let goals: NodeSelector = "re:.*X.*".parse().unwrap();
+    for _ in 0..20 {
+        let input = goal_rings(&[110, 111, 112, 113, 109], |_, _| rng.range(0, 2) == 0);
+        let network = Network::parse(&input);
+        let mut day8 = Day8::new();
+        day8.parse_input(&input);
+
+        let mut walks: Vec<_> = (0..5).map(|ring| network.walk(network.start(ring))).collect();
+        // Every walk has to take a step, so they're counted instead of stopping at the first one not on a goal.
+        let expected = (0..).find(|_| {
+            walks
+                .iter_mut()
+                .filter_map(Iterator::next)
+                .filter(|node| node.ends_with('X'))
+                .count()
+                == 5
+        });
+        assert_eq!(
+            day8.first_common_goal_step(&starts, &goals),
+            expected.map_or(CommonGoalStep::Never, CommonGoalStep::At),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn common_goal_step_never_with_incompatible_cycles() {
+    // The first two rings have even lengths, and goals only at even and only at odd steps respectively. Every other pair of
+    // rings has steps in common, and the cycles only all come round together after more than `u64::MAX` steps.
+    let mut rng = Rng::new(7);
+    let input = goal_rings(&[2018, 2026, 1019, 1021, 1031, 1033, 1039], |ring, node| match ring {
+        0 => node % 2 == 0,
+        1 => node % 2 == 1,
+        _ => rng.range(0, 2) == 0,
+    });
+    let mut day8 = Day8::new();
+    day8.parse_input(&input);
+    let starts: NodeSelector = "re:G.S.*".parse().unwrap();
+    let goals: NodeSelector = "re:.*X.*".parse().unwrap();
+    assert_eq!(day8.first_common_goal_step(&starts, &goals), CommonGoalStep::Never);
+}
+
+#[test]
+fn common_goal_step_matches_walking() {
+    let mut rng = Rng::new(42);
+    let [starts, goals] = ghost_selectors();
+    let mut checked = 0;
+    for _ in 0..CASES {
+        let input = random_network(&mut rng);
+        let Ok(expected) = Network::parse(&input).first_common_z_step(100_000) else {
+            continue;
+        };
+        let mut day8 = Day8::new();
+        day8.parse_input(&input);
+        assert_eq!(
+            day8.first_common_goal_step(&starts, &goals),
+            expected.map_or(CommonGoalStep::Never, CommonGoalStep::At),
+            "{input}"
+        );
+        checked += 1;
+    }
+    assert!(checked > CASES * 9 / 10);
+}
+
+#[test]
+fn common_goal_step_with_overflowing_lcm() {
+    // The product of these primes doesn't fit in 64 bits, but every ghost is on its Z node after 5 steps.
+    let lengths = [1009, 1013, 1019, 1021, 1031, 1033, 1039];
+    let mut day8 = Day8::new();
+    day8.parse_input(&rings("L", &lengths, |ring, node| match node {
+        0 => format!("R{ring}A"),
+        5 => format!("R{ring}Z"),
+        _ => format!("R{ring}N{node}"),
+    }));
+    let [starts, goals] = ghost_selectors();
+    assert_eq!(day8.first_common_goal_step(&starts, &goals), CommonGoalStep::At(5));
+    assert!(day8
+        .jump(&starts, &goals, 5)
+        .iter()
+        .all(|ghost| ghost.next_z_step == NextZStep::At(5)));
+
+    // With every ghost on its Z node after a different step, the first common one is after about 1.2 × 10²¹ steps.
+    day8.reset();
+    day8.parse_input(&rings("L", &lengths, |ring, node| match node {
+        0 => format!("R{ring}A"),
+        _ if node == ring + 1 => format!("R{ring}Z"),
+        _ => format!("R{ring}N{node}"),
+    }));
+    assert_eq!(day8.first_common_goal_step(&starts, &goals), CommonGoalStep::Undetermined);
+}
+
+#[test]
+fn common_goal_step_with_lead_ins_and_shared_factors() {
+    // AAA enters a cycle of 4 after 2 steps and is on a Z node every other step from then on. DDA enters a cycle of 6 after
+    // 1 step and is on a Z node after 3 and 6 steps in it, so the first step they share is 6.
+    let input = "L
+
+AAA = (BBB, BBB)
+BBB = (C0Z, C0Z)
+C0Z = (C1X, C1X)
+C1X = (C2Z, C2Z)
+C2Z = (C3X, C3X)
+C3X = (C0Z, C0Z)
+DDA = (E0X, E0X)
+E0X = (E1X, E1X)
+E1X = (E2Z, E2Z)
+E2Z = (E3X, E3X)
+E3X = (E4X, E4X)
+E4X = (E5Z, E5Z)
+E5Z = (E0X, E0X)";
+    let mut day8 = Day8::new();
+    day8.parse_input(input);
+    let [starts, goals] = ghost_selectors();
+    assert_eq!(Network::parse(input).first_common_z_step(100), Ok(Some(6)));
+    assert_eq!(day8.first_common_goal_step(&starts, &goals), CommonGoalStep::At(6));
+}
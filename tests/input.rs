@@ -0,0 +1,107 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use aoc2023::input::{self, DayInputs, Input};
+
+/// A directory under the system temp directory that is removed when dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> TempDir {
+        let dir = std::env::temp_dir().join(format!("aoc2023-input-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+
+    fn write(&self, path: &str, contents: &str) {
+        let path = self.0.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn input(expected: [Option<&str>; 2]) -> Input {
+    Input {
+        name: "example".to_owned(),
+        text: String::new(),
+        expected: expected.map(|answer| answer.map(str::to_owned)),
+    }
+}
+
+#[test]
+fn load_single_file() {
+    let dir = TempDir::new("single");
+    dir.write("day3", "467..114..\n");
+    match input::load_from(dir.path(), 3).unwrap() {
+        DayInputs::Single(text) => assert_eq!(text, "467..114..\n"),
+        DayInputs::Batch(_) => panic!("day3 is a single file"),
+    }
+    assert!(input::load_from(dir.path(), 4).is_err());
+}
+
+#[test]
+fn load_batch_directory() {
+    let dir = TempDir::new("batch");
+    dir.write("day3/b", "second\n");
+    dir.write("day3/a", "first\n");
+    dir.write("day3/a.expected", "4361\n467835\n");
+    dir.write("day3/c", "third\n");
+    dir.write("day3/c.expected", "\n467835");
+    dir.write("day3/d", "fourth\n");
+    dir.write("day3/d.expected", "4361");
+    let DayInputs::Batch(inputs) = input::load_from(dir.path(), 3).unwrap() else {
+        panic!("day3 is a directory");
+    };
+    let inputs: Vec<_> = inputs
+        .iter()
+        .map(|input| (input.name.as_str(), input.text.as_str(), input.expected.clone()))
+        .collect();
+    assert_eq!(
+        inputs,
+        [
+            ("a", "first\n", [Some("4361".to_owned()), Some("467835".to_owned())]),
+            ("b", "second\n", [None, None]),
+            ("c", "third\n", [None, Some("467835".to_owned())]),
+            ("d", "fourth\n", [Some("4361".to_owned()), None]),
+        ]
+    );
+}
+
+#[test]
+fn load_empty_batch_directory() {
+    let dir = TempDir::new("empty");
+    fs::create_dir(dir.path().join("day3")).unwrap();
+    let DayInputs::Batch(inputs) = input::load_from(dir.path(), 3).unwrap() else {
+        panic!("day3 is a directory");
+    };
+    assert!(inputs.is_empty());
+}
+
+#[test]
+fn check_answers() {
+    assert_eq!(input([None, None]).check(["4361", "467835"]), "-");
+    assert_eq!(input([Some("4361"), Some("467835")]).check(["4361", "467835"]), "ok");
+    assert_eq!(input([None, Some("467835")]).check(["0", "467835"]), "ok");
+    assert_eq!(
+        input([Some("4361"), None]).check(["4360", "467835"]),
+        "FAIL: part 1 should be 4361"
+    );
+    assert_eq!(
+        input([Some("4361"), Some("467835")]).check(["4360", "0"]),
+        "FAIL: part 1 should be 4361, part 2 should be 467835"
+    );
+}
@@ -0,0 +1,114 @@
+use super::set::{IncompatibleSetsError, Set};
+
+const BITS: usize = u64::BITS as usize;
+
+/// Contains a set of `usize` within the range `0..WORDS * 64`.
+///
+/// Implemented as a fixed-size bit field, so unlike [`IntSet`](super::intset::IntSet) it lives on the stack and never
+/// allocates. Adding a number outside of the range panics, while removing one does nothing and checking whether one is
+/// contained returns `false`.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct BitSet<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> BitSet<WORDS> {
+    /// The number of distinct numbers this [`BitSet`] can contain.
+    pub const CAPACITY: usize = WORDS * BITS;
+
+    pub const fn new() -> BitSet<WORDS> {
+        BitSet { words: [0; WORDS] }
+    }
+
+    /// Returns the amount of numbers in this [`BitSet`].
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns whether this [`BitSet`] has no numbers.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Returns an iterator of the numbers in this [`BitSet`], in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(word_index, &word)| {
+            let mut remaining = word;
+            std::iter::from_fn(move || {
+                (remaining != 0).then(|| {
+                    let bit_offset = remaining.trailing_zeros() as usize;
+                    remaining &= remaining - 1;
+                    word_index * BITS + bit_offset
+                })
+            })
+        })
+    }
+}
+
+impl<const WORDS: usize> Default for BitSet<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize> Set<usize> for BitSet<WORDS> {
+    /// Adds `item`. Nothing happens if `item` already exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `item` is not less than [`BitSet::CAPACITY`].
+    fn add(&mut self, item: usize) {
+        assert!(
+            item < Self::CAPACITY,
+            "{item} is outside of the BitSet range 0..{}",
+            Self::CAPACITY
+        );
+        self.words[item / BITS] |= 1 << (item % BITS);
+    }
+
+    fn remove(&mut self, item: usize) {
+        if item < Self::CAPACITY {
+            self.words[item / BITS] &= !(1 << (item % BITS));
+        }
+    }
+
+    fn clear(&mut self) {
+        self.words = [0; WORDS];
+    }
+
+    fn contains(&self, item: usize) -> bool {
+        item < Self::CAPACITY && self.words[item / BITS] & (1 << (item % BITS)) != 0
+    }
+
+    fn len(&self) -> usize {
+        self.count()
+    }
+
+    fn items(&self) -> impl Iterator<Item = usize> + '_ {
+        self.iter()
+    }
+
+    /// Removes all items that do not exist in `other`. Never fails, since both sets have the same capacity.
+    fn intersect(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            *word &= other;
+        }
+        Ok(())
+    }
+
+    /// Adds all items that exist in `other`. Never fails, since both sets have the same capacity.
+    fn union_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            *word |= other;
+        }
+        Ok(())
+    }
+
+    /// Removes all items that exist in `other`. Never fails, since both sets have the same capacity.
+    fn difference_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            *word &= !other;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The shape of an eventually periodic sequence `x0, f(x0), f(f(x0)), ...`, as found by [`brent`], [`floyd`] or
+/// [`with_memo`].
+///
+/// The elements at indices `lead_in..lead_in + length` repeat forever, while the ones before them never appear again.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct Cycle {
+    /// Number of elements before the cycle starts.
+    pub lead_in: usize,
+    /// Number of elements in the cycle, which is at least 1.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Returns the index of the first element that is equal to element `n`, which is below `lead_in + length`.
+    pub fn reduce(&self, n: u64) -> usize {
+        let lead_in = self.lead_in as u64;
+        if n < lead_in {
+            n as usize
+        } else {
+            self.lead_in + ((n - lead_in) % self.length as u64) as usize
+        }
+    }
+
+    /// Returns element `n` of the sequence starting at `start`, taking at most `lead_in + length` steps.
+    pub fn nth<T, F>(&self, start: T, mut step: F, n: u64) -> T
+    where
+        F: FnMut(&T) -> T,
+    {
+        (0..self.reduce(n)).fold(start, |value, _| step(&value))
+    }
+
+    /// Returns the indices below `lead_in + length` of the elements which `predicate` holds for, in ascending order. Every
+    /// other index it holds for is one of the indices from `lead_in` onward plus a multiple of `length`.
+    pub fn positions<T, F, P>(&self, start: T, mut step: F, mut predicate: P) -> Vec<usize>
+    where
+        F: FnMut(&T) -> T,
+        P: FnMut(&T) -> bool,
+    {
+        let mut positions = vec![];
+        let mut value = start;
+        for index in 0..self.lead_in + self.length {
+            if predicate(&value) {
+                positions.push(index);
+            }
+            value = step(&value);
+        }
+        positions
+    }
+}
+
+/// Returns the index of the first element which repeats, given the length of the cycle.
+fn find_lead_in<T, F>(start: T, step: &mut F, length: usize) -> usize
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut tortoise = start.clone();
+    let mut hare = (0..length).fold(start, |value, _| step(&value));
+    let mut lead_in = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        lead_in += 1;
+    }
+    lead_in
+}
+
+/// Finds the cycle of the sequence starting at `start` using Brent's algorithm, which takes fewer steps than [`floyd`] and
+/// keeps only two elements in memory.
+pub fn brent<T, F>(start: T, mut step: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    let lead_in = find_lead_in(start, &mut step, length);
+    Cycle { lead_in, length }
+}
+
+/// Finds the cycle of the sequence starting at `start` using Floyd's tortoise and hare algorithm, which keeps only two
+/// elements in memory.
+pub fn floyd<T, F>(start: T, mut step: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+    // The hare is now a multiple of the cycle length ahead of the tortoise, which is enough to find the lead-in.
+    let mut tortoise = start;
+    let mut lead_in = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        lead_in += 1;
+    }
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { lead_in, length }
+}
+
+/// Finds the cycle of the sequence starting at `start` by remembering every element, which takes the fewest steps. Returns
+/// the cycle along with the first `lead_in + length` elements.
+pub fn with_memo<T, F>(start: T, mut step: F) -> (Cycle, Vec<T>)
+where
+    T: Eq + Hash + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut indices: HashMap<T, usize> = HashMap::new();
+    let mut elements = vec![];
+    let mut value = start;
+    loop {
+        if let Some(&lead_in) = indices.get(&value) {
+            let length = elements.len() - lead_in;
+            return (Cycle { lead_in, length }, elements);
+        }
+        indices.insert(value.clone(), elements.len());
+        let next = step(&value);
+        elements.push(value);
+        value = next;
+    }
+}
+
+/// Returns element `n` of the eventually periodic sequence starting at `start`. Takes at most `n` steps, or a few times the
+/// steps until the sequence repeats if that's fewer, so `n` can be far larger than the number of distinct elements.
+pub fn nth<T, F>(start: T, mut step: F, n: u64) -> T
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    // This is Brent's algorithm, except it stops as soon as element `n` is reached.
+    let mut value = start.clone();
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut taken = 0;
+    loop {
+        if taken == n {
+            return value;
+        }
+        value = step(&value);
+        taken += 1;
+        if value == tortoise {
+            break;
+        }
+        if power == length {
+            tortoise = value.clone();
+            power *= 2;
+            length = 0;
+        }
+        length += 1;
+    }
+    let lead_in = find_lead_in(start.clone(), &mut step, length);
+    Cycle { lead_in, length }.nth(start, step, n)
+}
@@ -0,0 +1,325 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use super::intern::Interner;
+
+/// A directed graph stored as adjacency lists, whose nodes are named by strings.
+///
+/// Nodes are referred to by the dense indices their names are interned as, so they can be stored in `Vec`s. Every edge has a
+/// weight, which only matters for weighted searches such as [`Graph::dijkstra`], and a label of type `E`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Graph<E = ()> {
+    ids: Interner,
+    edges: Vec<Vec<Edge<E>>>,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct Edge<E> {
+    pub to: usize,
+    pub weight: u64,
+    pub label: E,
+}
+
+/// Distances and shortest paths from the start nodes of a search, as found by [`Graph::bfs`] and [`Graph::dijkstra`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Paths {
+    distances: Vec<Option<u64>>,
+    parents: Vec<Option<usize>>,
+}
+
+/// Error returned when sorting a graph with a cycle topologically.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct CyclicGraphError;
+
+impl<E> Graph<E> {
+    pub fn new() -> Graph<E> {
+        Graph {
+            ids: Interner::new(),
+            edges: vec![],
+        }
+    }
+
+    /// Returns the index of the node named `name`, adding the node if it doesn't exist yet.
+    pub fn add_node(&mut self, name: &str) -> usize {
+        let node = self.ids.intern(name);
+        if node == self.edges.len() {
+            self.edges.push(vec![]);
+        }
+        node
+    }
+
+    /// Adds an edge from `from` to `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node doesn't exist.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: u64, label: E) {
+        assert!(to < self.len(), "edges should lead to existing nodes");
+        self.edges[from].push(Edge { to, weight, label });
+    }
+
+    /// Returns the index of the node named `name`, or `None` if there's no such node.
+    pub fn node(&self, name: &str) -> Option<usize> {
+        self.ids.get(name)
+    }
+
+    /// Returns the name of `node`.
+    pub fn name(&self, node: usize) -> &str {
+        self.ids.name(node)
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Returns the edges leading out of `node`, in the order they were added.
+    pub fn edges(&self, node: usize) -> &[Edge<E>] {
+        &self.edges[node]
+    }
+
+    /// Returns the nodes that edges out of `node` lead to.
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[node].iter().map(|edge| edge.to)
+    }
+
+    /// Returns an iterator of every node along with its name.
+    pub fn nodes(&self) -> impl Iterator<Item = (usize, &str)> + '_ {
+        self.ids.iter()
+    }
+
+    /// Finds the paths with the fewest edges from any of `starts` to every node, using breadth-first search.
+    pub fn bfs<I: IntoIterator<Item = usize>>(&self, starts: I) -> Paths {
+        let mut paths = Paths::new(self.len());
+        let mut queue = VecDeque::new();
+        for start in starts {
+            if paths.distances[start].is_none() {
+                paths.distances[start] = Some(0);
+                queue.push_back(start);
+            }
+        }
+        while let Some(node) = queue.pop_front() {
+            let distance = paths.distances[node].unwrap();
+            for next in self.neighbors(node) {
+                if paths.distances[next].is_none() {
+                    paths.distances[next] = Some(distance + 1);
+                    paths.parents[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+        paths
+    }
+
+    /// Returns every node reachable from `start` in depth-first preorder, following edges in the order they were added.
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.len()];
+        let mut order = vec![];
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            order.push(node);
+            // Pushed in reverse, so that the first edge is followed first.
+            stack.extend(
+                self.neighbors(node)
+                    .filter(|&next| !visited[next])
+                    .collect::<Vec<usize>>()
+                    .into_iter()
+                    .rev(),
+            );
+        }
+        order
+    }
+
+    /// Finds the paths with the lowest total weight from any of `starts` to every node, using Dijkstra's algorithm.
+    pub fn dijkstra<I: IntoIterator<Item = usize>>(&self, starts: I) -> Paths {
+        let mut paths = Paths::new(self.len());
+        let mut queue = BinaryHeap::new();
+        for start in starts {
+            paths.distances[start] = Some(0);
+            queue.push(Reverse((0, start)));
+        }
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            if paths.distances[node].is_some_and(|best| best < distance) {
+                continue;
+            }
+            for edge in self.edges(node) {
+                let next_distance = distance + edge.weight;
+                if paths.distances[edge.to].is_none_or(|best| next_distance < best) {
+                    paths.distances[edge.to] = Some(next_distance);
+                    paths.parents[edge.to] = Some(node);
+                    queue.push(Reverse((next_distance, edge.to)));
+                }
+            }
+        }
+        paths
+    }
+
+    /// Finds a path with the lowest total weight from `start` to `goal` using A*, returning its weight and its nodes from
+    /// `start` to `goal`, or `None` if `goal` is unreachable.
+    ///
+    /// `heuristic` estimates the weight of the rest of the path from a node to `goal`. The path is only guaranteed to be the
+    /// lightest if the estimates are never too high. An estimate of 0 for every node makes this equivalent to Dijkstra's
+    /// algorithm.
+    pub fn a_star<H>(&self, start: usize, goal: usize, mut heuristic: H) -> Option<(u64, Vec<usize>)>
+    where
+        H: FnMut(usize) -> u64,
+    {
+        let mut paths = Paths::new(self.len());
+        let mut queue = BinaryHeap::new();
+        paths.distances[start] = Some(0);
+        queue.push(Reverse((heuristic(start), 0, start)));
+        while let Some(Reverse((_, distance, node))) = queue.pop() {
+            if paths.distances[node].is_some_and(|best| best < distance) {
+                continue;
+            }
+            if node == goal {
+                return Some((distance, paths.path_to(goal).unwrap()));
+            }
+            for edge in self.edges(node) {
+                let next_distance = distance + edge.weight;
+                if paths.distances[edge.to].is_none_or(|best| next_distance < best) {
+                    paths.distances[edge.to] = Some(next_distance);
+                    paths.parents[edge.to] = Some(node);
+                    queue.push(Reverse((next_distance + heuristic(edge.to), next_distance, edge.to)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns every node, ordered such that every edge leads from an earlier node to a later one. Returns an error if the
+    /// graph has a cycle, in which case there's no such order.
+    pub fn topological_sort(&self) -> Result<Vec<usize>, CyclicGraphError> {
+        let mut incoming = vec![0; self.len()];
+        for edges in self.edges.iter() {
+            for edge in edges {
+                incoming[edge.to] += 1;
+            }
+        }
+        let mut ready: Vec<usize> = (0..self.len()).filter(|&node| incoming[node] == 0).collect();
+        let mut order = vec![];
+        while let Some(node) = ready.pop() {
+            order.push(node);
+            for next in self.neighbors(node) {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    ready.push(next);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            Ok(order)
+        } else {
+            Err(CyclicGraphError)
+        }
+    }
+
+    /// Returns the strongly connected components, which are the largest groups of nodes that can all reach each other,
+    /// using Tarjan's algorithm.
+    ///
+    /// Components come in reverse topological order, so edges between components only lead to earlier components.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        const UNVISITED: usize = usize::MAX;
+        let mut indices = vec![UNVISITED; self.len()];
+        let mut low_links = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = vec![];
+        let mut components = vec![];
+        let mut next_index = 0;
+
+        for root in 0..self.len() {
+            if indices[root] != UNVISITED {
+                continue;
+            }
+            // Each frame is a node along with the position of the next edge to follow, replacing recursion.
+            let mut frames = vec![(root, 0)];
+            while let Some(&mut (node, ref mut edge)) = frames.last_mut() {
+                if *edge == 0 && indices[node] == UNVISITED {
+                    indices[node] = next_index;
+                    low_links[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+                if let Some(next) = self.edges[node].get(*edge).map(|edge| edge.to) {
+                    *edge += 1;
+                    if indices[next] == UNVISITED {
+                        frames.push((next, 0));
+                    } else if on_stack[next] {
+                        low_links[node] = low_links[node].min(indices[next]);
+                    }
+                    continue;
+                }
+
+                frames.pop();
+                if let Some(&(parent, _)) = frames.last() {
+                    low_links[parent] = low_links[parent].min(low_links[node]);
+                }
+                if low_links[node] == indices[node] {
+                    let mut component = vec![];
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+}
+
+impl<E> Default for Graph<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Paths {
+    fn new(len: usize) -> Paths {
+        Paths {
+            distances: vec![None; len],
+            parents: vec![None; len],
+        }
+    }
+
+    /// Returns the distance from the nearest start node to `node`, or `None` if `node` is unreachable.
+    pub fn distance(&self, node: usize) -> Option<u64> {
+        self.distances[node]
+    }
+
+    /// Returns the nodes of a shortest path from a start node to `node`, including both, or `None` if `node` is unreachable.
+    pub fn path_to(&self, node: usize) -> Option<Vec<usize>> {
+        self.distances[node]?;
+        let mut path = vec![node];
+        while let Some(parent) = self.parents[*path.last().unwrap()] {
+            path.push(parent);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl Display for CyclicGraphError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the graph has a cycle, so it can't be sorted topologically")
+    }
+}
+
+impl Error for CyclicGraphError {}
@@ -0,0 +1,224 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use super::pos::{Dir4, Dir8, Pos2d};
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// Positions are `Pos2d`s where `x` is the column and `y` is the row, starting from the top left corner. Methods that take a
+/// position accept any integer type, so that positions which may be out of bounds (such as negative ones) can be checked.
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Error returned when parsing a grid whose rows are of different lengths.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ParseGridError {
+    /// Index of the first row with a different length than the first row.
+    pub row: usize,
+    pub width: usize,
+    pub expected_width: usize,
+}
+
+/// Moves `value` one step in the direction given by `sign`, or returns `None` if that would go below zero.
+fn checked_step(value: usize, sign: Ordering) -> Option<usize> {
+    match sign {
+        Ordering::Less => value.checked_sub(1),
+        Ordering::Equal => Some(value),
+        Ordering::Greater => value.checked_add(1),
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a grid with one row per line of `text`, converting each character to a cell using `cell`.
+    pub fn parse<F>(text: &str, mut cell: F) -> Result<Grid<T>, ParseGridError>
+    where
+        F: FnMut(char) -> T,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (row, line) in text.lines().enumerate() {
+            let len_before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let row_width = cells.len() - len_before;
+            match width {
+                None => width = Some(row_width),
+                Some(expected_width) if expected_width != row_width => {
+                    return Err(ParseGridError {
+                        row,
+                        width: row_width,
+                        expected_width,
+                    })
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the index of `pos` in `cells`, or `None` if `pos` is out of bounds.
+    fn index_of<I: TryInto<usize>>(&self, pos: Pos2d<I>) -> Option<usize> {
+        let x: usize = pos.x.try_into().ok()?;
+        let y: usize = pos.y.try_into().ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    /// Returns whether `pos` is within this grid.
+    pub fn in_bounds<I: TryInto<usize>>(&self, pos: Pos2d<I>) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    /// Returns the cell at `pos`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds.
+    pub fn get<I: TryInto<usize>>(&self, pos: Pos2d<I>) -> &T {
+        self.try_get(pos).expect("position should be within the grid")
+    }
+
+    /// Returns the cell at `pos`, or `None` if `pos` is out of bounds.
+    pub fn try_get<I: TryInto<usize>>(&self, pos: Pos2d<I>) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    /// Returns a mutable reference to the cell at `pos`, or `None` if `pos` is out of bounds.
+    pub fn try_get_mut<I: TryInto<usize>>(&mut self, pos: Pos2d<I>) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// Sets the cell at `pos` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds.
+    pub fn set<I: TryInto<usize>>(&mut self, pos: Pos2d<I>, value: T) {
+        *self.try_get_mut(pos).expect("position should be within the grid") = value;
+    }
+
+    /// Returns an iterator of every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos2d<usize>, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (Pos2d::new(index % self.width, index / self.width), cell))
+    }
+
+    /// Returns the cells of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row should be within the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns an iterator of every row, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Returns an iterator of the cells in column `x`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column should be within the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Returns an iterator of every column, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    fn neighbors<D: Into<Dir8> + Copy>(
+        &self,
+        pos: Pos2d<usize>,
+        dirs: &'static [D],
+    ) -> impl Iterator<Item = (Pos2d<usize>, &T)> + '_ {
+        dirs.iter().filter_map(move |&dir| {
+            let (dx, dy) = dir.into().delta();
+            let neighbor = Pos2d::new(checked_step(pos.x, dx)?, checked_step(pos.y, dy)?);
+            Some((neighbor, self.try_get(neighbor)?))
+        })
+    }
+
+    /// Returns an iterator of the orthogonally adjacent cells of `pos` within the grid, along with their positions.
+    pub fn neighbors4(&self, pos: Pos2d<usize>) -> impl Iterator<Item = (Pos2d<usize>, &T)> + '_ {
+        self.neighbors(pos, &Dir4::ALL)
+    }
+
+    /// Returns an iterator of the orthogonally and diagonally adjacent cells of `pos` within the grid, along with their
+    /// positions.
+    pub fn neighbors8(&self, pos: Pos2d<usize>) -> impl Iterator<Item = (Pos2d<usize>, &T)> + '_ {
+        self.neighbors(pos, &Dir8::ALL)
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |ch| ch)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} is {} cells wide, but the grid is {} cells wide",
+            self.row, self.width, self.expected_width
+        )
+    }
+}
+
+impl Error for ParseGridError {}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+/// Maps strings to dense indices, so that things named by strings can be stored in a `Vec` and looked up in constant time.
+///
+/// Each distinct string gets the next free index the first time it's interned, starting from 0.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct Interner {
+    indices: HashMap<String, usize>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    /// Returns the index of `name`, giving it the next free index if it hasn't been interned before.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&index) = self.indices.get(name) {
+            return index;
+        }
+        let index = self.names.len();
+        self.indices.insert(name.to_owned(), index);
+        self.names.push(name.to_owned());
+        index
+    }
+
+    /// Returns the index of `name`, or `None` if it hasn't been interned.
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    /// Returns the string with index `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no string has been given `index`.
+    pub fn name(&self, index: usize) -> &str {
+        &self.names[index]
+    }
+
+    /// Returns the number of interned strings, which is also the next free index.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns whether no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns an iterator of every interned string along with its index, in order of their indices.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &str)> + '_ {
+        self.names.iter().map(String::as_str).enumerate()
+    }
+
+    /// Removes every string, so that indices start from 0 again.
+    pub fn clear(&mut self) {
+        self.indices.clear();
+        self.names.clear();
+    }
+}
@@ -0,0 +1,169 @@
+use std::ops::{Add, Range, Sub};
+
+use super::number::HasZero;
+
+/// A set of values stored as half-open ranges, which makes it possible to work with huge amounts of consecutive values.
+///
+/// The ranges are kept canonical: sorted, non-empty, and neither overlapping nor adjacent to each other. So two sets with the
+/// same values always have the same ranges.
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: vec![] }
+    }
+
+    /// Returns the canonical ranges of this set, in ascending order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// Returns whether this set has no values.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the lowest value in this set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// Returns the highest value in this set, plus one.
+    pub fn end(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end)
+    }
+
+    /// Returns the total number of values in this set.
+    pub fn len(&self) -> T
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + HasZero,
+    {
+        self.ranges.iter().fold(T::ZERO, |acc, range| acc + (range.end - range.start))
+    }
+
+    /// Returns whether `value` is in this set.
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(index).is_some_and(|range| range.start <= value)
+    }
+
+    /// Returns whether every value in `range` is in this set. Empty ranges are always contained.
+    pub fn contains_range(&self, range: Range<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let index = self.ranges.partition_point(|other| other.end < range.end);
+        self.ranges.get(index).is_some_and(|other| other.start <= range.start)
+    }
+
+    /// Adds every value in `range`.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // Ranges from `first` until `last` overlap or touch the new range, so they are merged into one.
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self.ranges.partition_point(|other| other.start <= range.end);
+        let merged = if first < last {
+            self.ranges[first].start.min(range.start)..self.ranges[last - 1].end.max(range.end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Removes every value in `range`.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // Ranges from `first` until `last` overlap the removed range, so only the parts outside of it are kept.
+        let first = self.ranges.partition_point(|other| other.end <= range.start);
+        let last = self.ranges.partition_point(|other| other.start < range.end);
+        if first >= last {
+            return;
+        }
+        let before = self.ranges[first].start..range.start;
+        let after = range.end..self.ranges[last - 1].end;
+        let kept = [before, after].into_iter().filter(|range| !range.is_empty());
+        self.ranges.splice(first..last, kept);
+    }
+
+    /// Returns the values in both `self` and `other`.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = vec![];
+        let (mut a, mut b) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+        while let (Some(range_a), Some(range_b)) = (a.peek(), b.peek()) {
+            let start = range_a.start.max(range_b.start);
+            let end = range_a.end.min(range_b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if range_a.end < range_b.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Returns the values in `self`, `other` or both.
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        other.ranges.iter().for_each(|range| union.insert(range.clone()));
+        union
+    }
+
+    /// Returns the values in `self` but not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        other.ranges.iter().for_each(|range| difference.remove(range.clone()));
+        difference
+    }
+
+    /// Returns a new set with `offset` added to every value.
+    pub fn translated(&self, offset: T) -> IntervalSet<T>
+    where
+        T: Add<T, Output = T>,
+    {
+        IntervalSet {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|range| range.start + offset..range.end + offset)
+                .collect(),
+        }
+    }
+}
+
+impl<T: Ord + Copy> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Copy> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ord + Copy> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|range| self.insert(range));
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use super::set::{IncompatibleSetsError, Set};
+
+const BITS: usize = usize::BITS as usize;
+
+/// Contains a set of `isize` within the range `min..max`.
+///
+/// Implemented as a bit field of size `max - min`.
+///
+/// Numbers outside of the range can never be members. Adding one panics (or fails, using [`IntSet::try_add`]), while removing
+/// one does nothing and checking whether one is contained returns `false`.
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct IntSet {
+    min: isize,
+    max: isize,
+    items: Vec<usize>,
+}
+
+/// Error returned when adding a number outside of the range of an [`IntSet`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct OutOfRangeError {
+    pub item: isize,
+    pub min: isize,
+    pub max: isize,
+}
+
+impl IntSet {
+    pub fn new(min: isize, max: isize) -> IntSet {
+        let num_items: usize = (max - min).try_into().unwrap();
+        let num_items = num_items.div_ceil(BITS);
+        IntSet {
+            min,
+            max,
+            items: vec![0; num_items],
+        }
+    }
+
+    /// Returns whether `item` is within the range of this [`IntSet`].
+    fn in_range(&self, item: isize) -> bool {
+        item >= self.min && item < self.max
+    }
+
+    /// Returns a tuple of the position a number would have in this [`IntSet`].
+    ///
+    /// The first tuple value is the index of a bit field in the `items` Vec, whereas the second value is the bit position
+    /// within that bit field.
+    fn bit_position(&self, item: isize) -> (usize, usize) {
+        let offset: usize = (item - self.min).try_into().unwrap();
+        (offset / BITS, offset % BITS)
+    }
+
+    /// Returns the number at bit `bit_offset` of bit field `item_index`.
+    fn item_at(&self, item_index: usize, bit_offset: usize) -> isize {
+        self.min + (item_index * BITS + bit_offset) as isize
+    }
+
+    /// Returns the minimum number that can exist in this [`IntSet`].
+    pub fn get_min(&self) -> isize {
+        self.min
+    }
+
+    /// Returns the maximum number that can exist in this [`IntSet`].
+    pub fn get_max(&self) -> isize {
+        self.max
+    }
+
+    /// Returns the amount of numbers in this [`IntSet`].
+    pub fn count(&self) -> usize {
+        self.items.iter().map(|item| item.count_ones() as usize).sum()
+    }
+
+    /// Returns whether this [`IntSet`] has no numbers.
+    pub fn is_empty(&self) -> bool {
+        self.items.iter().all(|&item| item == 0)
+    }
+
+    /// Adds `item`, or returns an error if `item` is outside of the range of this [`IntSet`].
+    pub fn try_add(&mut self, item: isize) -> Result<(), OutOfRangeError> {
+        if !self.in_range(item) {
+            return Err(OutOfRangeError {
+                item,
+                min: self.min,
+                max: self.max,
+            });
+        }
+        let (item_index, bit_offset) = self.bit_position(item);
+        self.items[item_index] |= 1 << bit_offset;
+        Ok(())
+    }
+
+    /// Returns an iterator of the numbers in this [`IntSet`], in ascending order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            set: self,
+            item_index: 0,
+            remaining: self.items.first().copied().unwrap_or(0),
+        }
+    }
+
+    /// Returns the lowest number in this [`IntSet`].
+    pub fn first(&self) -> Option<isize> {
+        self.iter().next()
+    }
+
+    /// Returns the highest number in this [`IntSet`].
+    pub fn last(&self) -> Option<isize> {
+        self.items
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, &item)| item != 0)
+            .map(|(item_index, item)| self.item_at(item_index, BITS - 1 - item.leading_zeros() as usize))
+    }
+
+    /// Returns the lowest number in this [`IntSet`] which is greater than `item`.
+    pub fn next_after(&self, item: isize) -> Option<isize> {
+        if item < self.min {
+            return self.first();
+        }
+        if item >= self.max - 1 {
+            return None;
+        }
+        let (item_index, bit_offset) = self.bit_position(item + 1);
+        Iter {
+            set: self,
+            item_index,
+            remaining: self.items[item_index] & (usize::MAX << bit_offset),
+        }
+        .next()
+    }
+
+    /// Returns an error if `other` has a different range than `self`.
+    fn check_compatible(&self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        if self.min != other.min || self.max != other.max {
+            return Err(IncompatibleSetsError);
+        }
+        Ok(())
+    }
+
+    /// Adds all numbers that exist in `other`.
+    ///
+    /// Returns an error and leaves `self` unchanged if `other` has a different range than `self`.
+    pub fn union(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.check_compatible(other)?;
+        for (item, other) in self.items.iter_mut().zip(other.items.iter()) {
+            *item |= other;
+        }
+        Ok(())
+    }
+
+    /// Removes all numbers that exist in `other`.
+    ///
+    /// Returns an error and leaves `self` unchanged if `other` has a different range than `self`.
+    pub fn difference(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.check_compatible(other)?;
+        for (item, other) in self.items.iter_mut().zip(other.items.iter()) {
+            *item &= !other;
+        }
+        Ok(())
+    }
+
+    /// Keeps the numbers that exist in exactly one of `self` and `other`.
+    ///
+    /// Returns an error and leaves `self` unchanged if `other` has a different range than `self`.
+    pub fn symmetric_difference(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.check_compatible(other)?;
+        for (item, other) in self.items.iter_mut().zip(other.items.iter()) {
+            *item ^= other;
+        }
+        Ok(())
+    }
+
+    /// Returns whether every number in `self` also exists in `other`. The sets may have different ranges.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        if self.min == other.min && self.max == other.max {
+            self.items
+                .iter()
+                .zip(other.items.iter())
+                .all(|(item, other)| item & !other == 0)
+        } else {
+            self.iter().all(|item| other.contains(item))
+        }
+    }
+
+    /// Returns whether every number in `other` also exists in `self`. The sets may have different ranges.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns whether `self` and `other` have no numbers in common. The sets may have different ranges.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        if self.min == other.min && self.max == other.max {
+            self.items
+                .iter()
+                .zip(other.items.iter())
+                .all(|(item, other)| item & other == 0)
+        } else {
+            self.iter().all(|item| !other.contains(item))
+        }
+    }
+}
+
+impl Set<isize> for IntSet {
+    /// Adds `item`. Nothing happens if `item` already exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `item` is outside of the range of this [`IntSet`].
+    fn add(&mut self, item: isize) {
+        if let Err(err) = self.try_add(item) {
+            panic!("{err}");
+        }
+    }
+
+    fn remove(&mut self, item: isize) {
+        if !self.in_range(item) {
+            return;
+        }
+        let (item_index, bit_offset) = self.bit_position(item);
+        self.items[item_index] &= !(1 << bit_offset);
+    }
+
+    fn clear(&mut self) {
+        self.items.fill(0);
+    }
+
+    fn contains(&self, item: isize) -> bool {
+        if !self.in_range(item) {
+            return false;
+        }
+        let (item_index, bit_offset) = self.bit_position(item);
+        (self.items[item_index] & (1 << bit_offset)) != 0
+    }
+
+    fn len(&self) -> usize {
+        self.count()
+    }
+
+    fn items(&self) -> impl Iterator<Item = isize> + '_ {
+        self.iter()
+    }
+
+    fn intersect(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.check_compatible(other)?;
+        for (item, other) in self.items.iter_mut().zip(other.items.iter()) {
+            *item &= other;
+        }
+        Ok(())
+    }
+
+    fn union_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.union(other)
+    }
+
+    fn difference_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.difference(other)
+    }
+}
+
+/// Iterator of the numbers in an [`IntSet`] in ascending order, created by [`IntSet::iter`].
+pub struct Iter<'a> {
+    set: &'a IntSet,
+    item_index: usize,
+    /// Bits of the current bit field which have not been returned yet.
+    remaining: usize,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = isize;
+
+    fn next(&mut self) -> Option<isize> {
+        while self.remaining == 0 {
+            self.item_index += 1;
+            self.remaining = *self.set.items.get(self.item_index)?;
+        }
+        let bit_offset = self.remaining.trailing_zeros() as usize;
+        self.remaining &= self.remaining - 1;
+        Some(self.set.item_at(self.item_index, bit_offset))
+    }
+}
+
+impl<'a> IntoIterator for &'a IntSet {
+    type Item = isize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl FromIterator<isize> for IntSet {
+    /// Collects the numbers into an [`IntSet`] with the smallest range that contains all of them.
+    fn from_iter<I: IntoIterator<Item = isize>>(iter: I) -> Self {
+        let items: Vec<isize> = iter.into_iter().collect();
+        let min = items.iter().copied().min().unwrap_or(0);
+        let max = items.iter().copied().max().map_or(0, |max| max + 1);
+        let mut set = IntSet::new(min, max);
+        set.extend(items);
+        set
+    }
+}
+
+impl Extend<isize> for IntSet {
+    /// Adds every number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any number is outside of the range of this [`IntSet`].
+    fn extend<I: IntoIterator<Item = isize>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.add(item));
+    }
+}
+
+impl Display for OutOfRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is outside of the IntSet range {}..{}", self.item, self.min, self.max)
+    }
+}
+
+impl Error for OutOfRangeError {}
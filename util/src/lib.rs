@@ -0,0 +1,17 @@
+//! Utilities for Advent of Code solutions, shared between years so they don't have to be copied around.
+
+pub mod array;
+pub mod bitset;
+pub mod cycle;
+pub mod graph;
+pub mod grid;
+pub mod interleave;
+pub mod intern;
+pub mod interval;
+pub mod intset;
+pub mod number;
+pub mod pattern;
+pub mod pos;
+pub mod set;
+pub mod top;
+pub mod try_index;
@@ -0,0 +1,342 @@
+use std::ops::{Div, Mul, Rem};
+
+/// Types that implement this trait can be used to calculate the greatest common denominator (GCD) and lowest common multiple
+/// (LCM).
+pub trait Gcd
+where
+    Self: Sized
+        + Mul<Self, Output = Self>
+        + Div<Self, Output = Self>
+        + Rem<Self, Output = Self>
+        + PartialEq<Self>
+        + HasZero
+        + Checked
+        + Copy,
+{
+    fn gcd(self, other: Self) -> Self {
+        if other == Self::ZERO {
+            self
+        } else {
+            other.gcd(self % other)
+        }
+    }
+
+    fn lcm(self, other: Self) -> Self {
+        (self / self.gcd(other)) * other
+    }
+
+    /// Returns the LCM, or `None` if it overflows.
+    fn checked_lcm(self, other: Self) -> Option<Self> {
+        if self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+        (self / self.gcd(other)).checked_mul(other)
+    }
+}
+
+/// Returns the LCM of every number, which is 1 if there are none.
+pub fn lcm_all<T: Gcd + HasOne, I: IntoIterator<Item = T>>(iter: I) -> T {
+    iter.into_iter().fold(T::ONE, T::lcm)
+}
+
+/// Returns the LCM of every number, which is 1 if there are none, or `None` if it overflows.
+pub fn checked_lcm_all<T: Gcd + HasOne, I: IntoIterator<Item = T>>(iter: I) -> Option<T> {
+    iter.into_iter().try_fold(T::ONE, T::checked_lcm)
+}
+
+macro_rules! impl_gcd {
+    ($($t:ty)*) => ($(
+        impl Gcd for $t {}
+    )*)
+}
+
+impl_gcd! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+/// Types that implement this trait indicates that they have a zero-value. The zero-value will be in `Self::ZERO`.
+pub trait HasZero {
+    const ZERO: Self;
+}
+
+macro_rules! impl_haszero {
+    ($($t:ty)*) => ($(
+        impl HasZero for $t {
+            const ZERO: Self = 0;
+        }
+    )*)
+}
+
+impl_haszero! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+/// Types that implement this trait indicates that they have a one-value. The one-value will be in `Self::ONE`.
+pub trait HasOne {
+    const ONE: Self;
+}
+
+macro_rules! impl_hasone {
+    ($($t:ty)*) => ($(
+        impl HasOne for $t {
+            const ONE: Self = 1;
+        }
+    )*)
+}
+
+impl_hasone! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+/// Types that implement this trait have arithmetic operations which return `None` instead of overflowing.
+pub trait Checked
+where
+    Self: Sized,
+{
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked {
+    ($($t:ty)*) => ($(
+        impl Checked for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        }
+    )*)
+}
+
+impl_checked! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+/// Signed integer types, which can calculate the coefficients of Bézout's identity.
+pub trait ExtendedGcd
+where
+    Self: Sized,
+{
+    /// Returns `(gcd, x, y)` such that `self * x + other * y == gcd`, using the extended Euclidean algorithm.
+    fn extended_gcd(self, other: Self) -> (Self, Self, Self);
+}
+
+macro_rules! impl_extended_gcd {
+    ($($t:ty)*) => ($(
+        impl ExtendedGcd for $t {
+            fn extended_gcd(self, other: Self) -> (Self, Self, Self) {
+                let (mut old_r, mut r) = (self, other);
+                let (mut old_x, mut x) = (1, 0);
+                let (mut old_y, mut y) = (0, 1);
+                while r != 0 {
+                    let quotient = old_r / r;
+                    (old_r, r) = (r, old_r - quotient * r);
+                    (old_x, x) = (x, old_x - quotient * x);
+                    (old_y, y) = (y, old_y - quotient * y);
+                }
+                (old_r, old_x, old_y)
+            }
+        }
+    )*)
+}
+
+impl_extended_gcd! { isize i8 i16 i32 i64 i128 }
+
+/// Error returned when combining congruences with [`Modular::combine_congruences`] or [`crt`] fails.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CongruenceError {
+    /// No number satisfies every congruence.
+    NoSolution,
+    /// The congruences might have a solution, but the LCM of their moduli doesn't fit in the type.
+    Overflow,
+}
+
+/// Unsigned integer types of up to 64 bits, which support modular arithmetic without overflowing.
+///
+/// Every method panics if `modulus` is 0.
+pub trait Modular
+where
+    Self: Sized + Copy,
+{
+    /// Returns `self * rhs % modulus`.
+    fn mod_mul(self, rhs: Self, modulus: Self) -> Self;
+
+    /// Returns `self` to the power of `exp`, modulo `modulus`.
+    fn mod_pow(self, exp: Self, modulus: Self) -> Self;
+
+    /// Returns the number `x` in `0..modulus` such that `self * x % modulus == 1`, or `None` if `self` and `modulus` aren't
+    /// coprime.
+    fn mod_inverse(self, modulus: Self) -> Option<Self>;
+
+    /// Combines the congruences `x ≡ r1 (mod m1)` and `x ≡ r2 (mod m2)` into `x ≡ r (mod lcm(m1, m2))`, returned as
+    /// `(r, lcm)` with `r` in `0..lcm`. The moduli don't need to be coprime.
+    ///
+    /// Returns [`CongruenceError::NoSolution`] if no `x` satisfies both congruences, or [`CongruenceError::Overflow`] if some
+    /// do but the LCM overflows.
+    fn combine_congruences(a: (Self, Self), b: (Self, Self)) -> Result<(Self, Self), CongruenceError>;
+}
+
+macro_rules! impl_modular {
+    ($($t:ty)*) => ($(
+        impl Modular for $t {
+            fn mod_mul(self, rhs: Self, modulus: Self) -> Self {
+                (self as u128 * rhs as u128 % modulus as u128) as $t
+            }
+
+            fn mod_pow(self, exp: Self, modulus: Self) -> Self {
+                let mut result = 1 % modulus;
+                let mut base = self % modulus;
+                let mut exp = exp;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        result = result.mod_mul(base, modulus);
+                    }
+                    base = base.mod_mul(base, modulus);
+                    exp >>= 1;
+                }
+                result
+            }
+
+            fn mod_inverse(self, modulus: Self) -> Option<Self> {
+                let (gcd, x, _) = ((self % modulus) as i128).extended_gcd(modulus as i128);
+                (gcd == 1).then(|| x.rem_euclid(modulus as i128) as $t)
+            }
+
+            fn combine_congruences(
+                (r1, m1): (Self, Self),
+                (r2, m2): (Self, Self),
+            ) -> Result<(Self, Self), CongruenceError> {
+                let (r1, r2) = (r1 % m1, r2 % m2);
+                let (gcd, x, _) = (m1 as i128).extended_gcd(m2 as i128);
+                let difference = r2 as i128 - r1 as i128;
+                if difference % gcd != 0 {
+                    return Err(CongruenceError::NoSolution);
+                }
+                // The LCM can take up to 128 bits, so it's checked to fit before anything is multiplied by it.
+                let lcm: $t = (m1 as u128 / gcd as u128 * m2 as u128)
+                    .try_into()
+                    .map_err(|_| CongruenceError::Overflow)?;
+                let m2_gcd = (m2 as i128 / gcd) as $t;
+                // `k * m1 ≡ r2 - r1 (mod m2)` with `k` below `m2 / gcd`, which makes `r1 + k * m1` smaller than the LCM.
+                let k = ((difference / gcd).rem_euclid(m2_gcd as i128) as $t)
+                    .mod_mul(x.rem_euclid(m2_gcd as i128) as $t, m2_gcd);
+                Ok((r1 + k * m1, lcm))
+            }
+        }
+    )*)
+}
+
+impl_modular! { usize u8 u16 u32 u64 }
+
+/// Solves a system of congruences `x ≡ r (mod m)`, given as `(r, m)` pairs, using the Chinese Remainder Theorem. Returns the
+/// solution as `(x, lcm)`, where `x` is the smallest solution and every solution is congruent to it modulo `lcm`, the LCM of
+/// every modulus. The moduli don't need to be coprime.
+///
+/// Returns the error of the first pair of congruences that can't be combined, as described in [`Modular::combine_congruences`].
+/// Once the LCM overflows, the congruences after it aren't checked for a solution.
+pub fn crt<T: Modular + HasZero + HasOne, I: IntoIterator<Item = (T, T)>>(congruences: I) -> Result<(T, T), CongruenceError> {
+    congruences.into_iter().try_fold((T::ZERO, T::ONE), |combined, congruence| {
+        T::combine_congruences(combined, congruence)
+    })
+}
+
+/// Unsigned integer types of up to 64 bits, which can be tested for primality and factorized.
+pub trait Primes
+where
+    Self: Sized + Copy,
+{
+    /// Returns whether `self` is prime, using a deterministic Miller–Rabin test.
+    fn is_prime(self) -> bool;
+
+    /// Returns the prime factors of `self` along with their exponents, in ascending order. 0 and 1 have no prime factors.
+    fn prime_factors(self) -> Vec<(Self, u32)>;
+}
+
+/// Bases which make the Miller–Rabin test deterministic for every 64-bit number.
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if let Some(&base) = MILLER_RABIN_BASES.iter().find(|&&base| n.is_multiple_of(base)) {
+        return n == base;
+    }
+    let trailing_zeros = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> trailing_zeros;
+    MILLER_RABIN_BASES.iter().all(|&base| {
+        let mut x = base.mod_pow(odd, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..trailing_zeros {
+            x = x.mod_mul(x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Returns a nontrivial factor of the odd composite number `n`, using Pollard's rho algorithm.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let step = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y, mut divisor) = (2, 2, 1);
+        while divisor == 1 {
+            x = step(x);
+            y = step(step(y));
+            divisor = x.abs_diff(y).gcd(n);
+        }
+        // The sequence may cycle without finding a factor, in which case another constant is tried.
+        if divisor != n {
+            return divisor;
+        }
+    }
+    unreachable!()
+}
+
+/// Pushes the prime factors of `n` onto `factors`, unordered and with repetitions.
+fn factorize_u64(n: u64, factors: &mut Vec<u64>) {
+    if n < 2 {
+        return;
+    }
+    if is_prime_u64(n) {
+        factors.push(n);
+        return;
+    }
+    if let Some(&prime) = MILLER_RABIN_BASES.iter().find(|&&prime| n.is_multiple_of(prime)) {
+        factors.push(prime);
+        return factorize_u64(n / prime, factors);
+    }
+    let divisor = pollard_rho(n);
+    factorize_u64(divisor, factors);
+    factorize_u64(n / divisor, factors);
+}
+
+macro_rules! impl_primes {
+    ($($t:ty)*) => ($(
+        impl Primes for $t {
+            fn is_prime(self) -> bool {
+                is_prime_u64(self as u64)
+            }
+
+            fn prime_factors(self) -> Vec<(Self, u32)> {
+                let mut factors = vec![];
+                factorize_u64(self as u64, &mut factors);
+                factors.sort_unstable();
+                let mut counted: Vec<(Self, u32)> = vec![];
+                for factor in factors {
+                    match counted.last_mut() {
+                        Some((last, count)) if *last as u64 == factor => *count += 1,
+                        _ => counted.push((factor as $t, 1)),
+                    }
+                }
+                counted
+            }
+        }
+    )*)
+}
+
+impl_primes! { usize u8 u16 u32 u64 }
@@ -0,0 +1,175 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    iter::Peekable,
+    str::{Chars, FromStr},
+};
+
+/// A small subset of regular expressions, which always match whole strings.
+///
+/// Supported are literal characters, `.` for any character, classes like `[A-Z]` and `[^0-9]`, and the quantifiers `*`, `+`
+/// and `?` after any of those. A `\` makes the next character literal. Matching tracks every position in the pattern at once
+/// instead of backtracking, so it takes `O(pattern length × text length)` time.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Pattern {
+    tokens: Vec<Token>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+enum Atom {
+    Char(char),
+    Any,
+    /// Matches characters within any of the inclusive ranges, or outside all of them if negated.
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+struct Token {
+    atom: Atom,
+    /// Whether the token can match nothing.
+    optional: bool,
+    /// Whether the token can match any number of characters in a row.
+    repeated: bool,
+}
+
+/// Error returned when parsing an invalid [`Pattern`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum PatternError {
+    /// The quantifier doesn't follow something it can repeat.
+    MissingAtom(char),
+    /// A `[` has no matching `]`.
+    UnclosedClass,
+    /// The pattern ends with a `\`.
+    TrailingEscape,
+}
+
+impl Atom {
+    fn matches(&self, ch: char) -> bool {
+        match self {
+            Atom::Char(expected) => ch == *expected,
+            Atom::Any => true,
+            Atom::Class { negated, ranges } => ranges.iter().any(|&(start, end)| (start..=end).contains(&ch)) != *negated,
+        }
+    }
+}
+
+/// Parses a character class after its `[`, up to and including its `]`. A `]` right after the `[` or `[^` is literal, as is
+/// a `-` at the start or end.
+fn parse_class(chars: &mut Peekable<Chars>) -> Result<Atom, PatternError> {
+    let negated = chars.next_if_eq(&'^').is_some();
+    let mut ranges = vec![];
+    let next_char = |chars: &mut Peekable<Chars>| match chars.next() {
+        Some('\\') => chars.next().ok_or(PatternError::UnclosedClass),
+        ch => ch.ok_or(PatternError::UnclosedClass),
+    };
+    loop {
+        if !ranges.is_empty() && chars.next_if_eq(&']').is_some() {
+            return Ok(Atom::Class { negated, ranges });
+        }
+        let start = next_char(chars)?;
+        let mut end = start;
+        if chars.peek() == Some(&'-') {
+            chars.next();
+            if chars.peek() == Some(&']') {
+                ranges.push(('-', '-'));
+            } else {
+                end = next_char(chars)?;
+            }
+        }
+        ranges.push((start, end));
+    }
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Result<Pattern, PatternError> {
+        let mut tokens: Vec<Token> = vec![];
+        let mut chars = pattern.chars().peekable();
+        while let Some(ch) = chars.next() {
+            let atom = match ch {
+                '*' | '+' | '?' => {
+                    let Some(last) = tokens.last_mut().filter(|token| !token.optional && !token.repeated) else {
+                        return Err(PatternError::MissingAtom(ch));
+                    };
+                    match ch {
+                        '*' => (last.optional, last.repeated) = (true, true),
+                        '?' => last.optional = true,
+                        // One or more is one followed by zero or more.
+                        _ => {
+                            let atom = last.atom.clone();
+                            tokens.push(Token {
+                                atom,
+                                optional: true,
+                                repeated: true,
+                            });
+                        }
+                    }
+                    continue;
+                }
+                '.' => Atom::Any,
+                '\\' => Atom::Char(chars.next().ok_or(PatternError::TrailingEscape)?),
+                '[' => parse_class(&mut chars)?,
+                ch => Atom::Char(ch),
+            };
+            tokens.push(Token {
+                atom,
+                optional: false,
+                repeated: false,
+            });
+        }
+        Ok(Pattern { tokens })
+    }
+
+    /// Adds the positions that can be reached from `positions` by skipping optional tokens.
+    fn skip_optional(&self, positions: &mut [bool]) {
+        for (index, token) in self.tokens.iter().enumerate() {
+            if positions[index] && token.optional {
+                positions[index + 1] = true;
+            }
+        }
+    }
+
+    /// Returns whether the pattern matches the whole of `text`.
+    pub fn is_match(&self, text: &str) -> bool {
+        // `positions[i]` is whether the text so far can be matched by the first `i` tokens.
+        let mut positions = vec![false; self.tokens.len() + 1];
+        positions[0] = true;
+        self.skip_optional(&mut positions);
+        for ch in text.chars() {
+            let mut next_positions = vec![false; self.tokens.len() + 1];
+            for (index, token) in self.tokens.iter().enumerate() {
+                if positions[index] && token.atom.matches(ch) {
+                    next_positions[if token.repeated { index } else { index + 1 }] = true;
+                }
+            }
+            self.skip_optional(&mut next_positions);
+            if !next_positions.contains(&true) {
+                return false;
+            }
+            positions = next_positions;
+        }
+        positions[self.tokens.len()]
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Pattern::new(s)
+    }
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternError::MissingAtom(quantifier) => write!(f, "'{quantifier}' should follow something to repeat"),
+            PatternError::UnclosedClass => write!(f, "'[' should be closed by ']'"),
+            PatternError::TrailingEscape => write!(f, "'\\' should be followed by a character"),
+        }
+    }
+}
+
+impl Error for PatternError {}
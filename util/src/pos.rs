@@ -0,0 +1,977 @@
+use std::{
+    cmp::{max, Ordering},
+    fmt::{Debug, Display},
+    ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub},
+};
+
+use super::number::{Checked, HasOne, HasZero};
+
+/// One of the four orthogonal directions.
+///
+/// Directions follow the same convention as text, where `y` grows downwards. So [`Dir4::Up`] decreases `y`.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the four orthogonal or four diagonal directions, with `y` growing downwards like [`Dir4`].
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir4 {
+    /// All directions in clockwise order, starting from [`Dir4::Up`].
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    fn from_index(index: usize) -> Dir4 {
+        Self::ALL[index % Self::ALL.len()]
+    }
+
+    /// Returns the direction 90° clockwise of this one.
+    pub fn turn_right(self) -> Dir4 {
+        Self::from_index(self as usize + 1)
+    }
+
+    /// Returns the direction 90° counterclockwise of this one.
+    pub fn turn_left(self) -> Dir4 {
+        Self::from_index(self as usize + 3)
+    }
+
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Dir4 {
+        Self::from_index(self as usize + 2)
+    }
+}
+
+impl Dir8 {
+    /// All directions in clockwise order, starting from [`Dir8::Up`].
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    fn from_index(index: usize) -> Dir8 {
+        Self::ALL[index % Self::ALL.len()]
+    }
+
+    /// Returns the direction 45° clockwise of this one.
+    pub fn turn_right(self) -> Dir8 {
+        Self::from_index(self as usize + 1)
+    }
+
+    /// Returns the direction 45° counterclockwise of this one.
+    pub fn turn_left(self) -> Dir8 {
+        Self::from_index(self as usize + 7)
+    }
+
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Dir8 {
+        Self::from_index(self as usize + 4)
+    }
+
+    /// Returns the signs of the `x` and `y` offsets when moving in this direction.
+    pub fn delta(self) -> (Ordering, Ordering) {
+        use Ordering::{Equal, Greater, Less};
+        match self {
+            Dir8::Up => (Equal, Less),
+            Dir8::UpRight => (Greater, Less),
+            Dir8::Right => (Greater, Equal),
+            Dir8::DownRight => (Greater, Greater),
+            Dir8::Down => (Equal, Greater),
+            Dir8::DownLeft => (Less, Greater),
+            Dir8::Left => (Less, Equal),
+            Dir8::UpLeft => (Less, Less),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::Up,
+            Dir4::Right => Dir8::Right,
+            Dir4::Down => Dir8::Down,
+            Dir4::Left => Dir8::Left,
+        }
+    }
+}
+
+/// Moves `value` one step in the direction given by `sign`.
+fn step_towards<T>(value: T, sign: Ordering) -> T
+where
+    T: Add<T, Output = T> + Sub<T, Output = T> + HasOne,
+{
+    match sign {
+        Ordering::Less => value - T::ONE,
+        Ordering::Equal => value,
+        Ordering::Greater => value + T::ONE,
+    }
+}
+
+/// Returns the absolute difference between `a` and `b`, which also works for unsigned types.
+fn abs_diff<T>(a: T, b: T) -> T
+where
+    T: Sub<T, Output = T> + PartialOrd<T>,
+{
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Default, Hash, Debug)]
+pub struct Pos2d<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Pos2d<T> {
+    pub fn new(x: T, y: T) -> Pos2d<T> {
+        Pos2d { x, y }
+    }
+
+    /// Adds `x` and `y` with this position's `x` and `y` and returns the result in a new [`Pos2d<T>`].
+    pub fn add(&self, x: T, y: T) -> Pos2d<T>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Pos2d {
+            x: self.x + x,
+            y: self.y + y,
+        }
+    }
+
+    /// Returns a new [`Pos2d<T>`] with the absolute values of `x` and `y`.
+    pub fn abs(&self) -> Pos2d<T>
+    where
+        T: Neg<Output = T> + PartialOrd<T> + HasZero + Copy,
+    {
+        Pos2d::new(
+            if self.x < T::ZERO { -self.x } else { self.x },
+            if self.y < T::ZERO { -self.y } else { self.y },
+        )
+    }
+
+    /// Returns the maximum of `x` and `y`.
+    pub fn max(&self) -> T
+    where
+        T: Ord + Copy,
+    {
+        max(self.x, self.y)
+    }
+
+    /// Returns the sum of `x` and `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` or `y` can't be converted into type `S`.
+    pub fn sum<S>(&self) -> S
+    where
+        S: Add<S, Output = S> + TryFrom<T>,
+        <S as TryFrom<T>>::Error: Debug,
+        T: Copy,
+    {
+        <S as TryFrom<T>>::try_from(self.x).unwrap() + <S as TryFrom<T>>::try_from(self.y).unwrap()
+    }
+
+    /// Returns the adjacent position in direction `dir`.
+    pub fn step<D: Into<Dir8>>(&self, dir: D) -> Pos2d<T>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + HasOne + Copy,
+    {
+        let (dx, dy) = dir.into().delta();
+        Pos2d::new(step_towards(self.x, dx), step_towards(self.y, dy))
+    }
+
+    /// Returns an iterator of the four orthogonally adjacent positions, in the order of [`Dir4::ALL`].
+    pub fn neighbors4(&self) -> impl Iterator<Item = Pos2d<T>>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + HasOne + Copy,
+    {
+        let pos = *self;
+        Dir4::ALL.into_iter().map(move |dir| pos.step(dir))
+    }
+
+    /// Returns an iterator of the eight orthogonally and diagonally adjacent positions, in the order of [`Dir8::ALL`].
+    pub fn neighbors8(&self) -> impl Iterator<Item = Pos2d<T>>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + HasOne + Copy,
+    {
+        let pos = *self;
+        Dir8::ALL.into_iter().map(move |dir| pos.step(dir))
+    }
+
+    /// Returns the Manhattan distance to `other`, i.e. the sum of the distances along each axis.
+    pub fn manhattan_distance(&self, other: Pos2d<T>) -> T
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + PartialOrd<T> + Copy,
+    {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Returns the Chebyshev distance to `other`, i.e. the greatest of the distances along each axis.
+    pub fn chebyshev_distance(&self, other: Pos2d<T>) -> T
+    where
+        T: Sub<T, Output = T> + PartialOrd<T> + Copy,
+    {
+        let dx = abs_diff(self.x, other.x);
+        let dy = abs_diff(self.y, other.y);
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    /// Returns a new [`Pos2d<T>`] with the lesser of each component in this position and `other`.
+    pub fn component_min(&self, other: Pos2d<T>) -> Pos2d<T>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        Pos2d::new(partial_min(self.x, other.x), partial_min(self.y, other.y))
+    }
+
+    /// Returns a new [`Pos2d<T>`] with the greater of each component in this position and `other`.
+    pub fn component_max(&self, other: Pos2d<T>) -> Pos2d<T>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        Pos2d::new(partial_max(self.x, other.x), partial_max(self.y, other.y))
+    }
+
+    /// Rotates this position 90° clockwise about `center`, with `y` growing downwards as in [`Dir4`].
+    pub fn rotate_right(&self, center: Pos2d<T>) -> Pos2d<T>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + Copy,
+    {
+        Pos2d::new(center.x + center.y - self.y, center.y + self.x - center.x)
+    }
+
+    /// Rotates this position 90° counterclockwise about `center`, with `y` growing downwards as in [`Dir4`].
+    pub fn rotate_left(&self, center: Pos2d<T>) -> Pos2d<T>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + Copy,
+    {
+        Pos2d::new(center.x + self.y - center.y, center.y + center.x - self.x)
+    }
+}
+
+impl<T: Display> Display for Pos2d<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<T, Output = T>> Add<Pos2d<T>> for Pos2d<T> {
+    type Output = Pos2d<T>;
+
+    fn add(self, rhs: Pos2d<T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<T, Output = T>> Sub<Pos2d<T>> for Pos2d<T> {
+    type Output = Pos2d<T>;
+
+    fn sub(self, rhs: Pos2d<T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Mul<T, Output = T> + Copy> Mul<T> for Pos2d<T> {
+    type Output = Pos2d<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Div<T, Output = T> + Copy> Div<T> for Pos2d<T> {
+    type Output = Pos2d<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+/// Returns the lesser of `a` and `b`, which unlike [`std::cmp::min`] only requires [`PartialOrd`].
+fn partial_min<T: PartialOrd<T>>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns the greater of `a` and `b`, which unlike [`std::cmp::max`] only requires [`PartialOrd`].
+fn partial_max<T: PartialOrd<T>>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Default, Hash, Debug)]
+pub struct Pos3d<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Pos3d<T> {
+    pub fn new(x: T, y: T, z: T) -> Pos3d<T> {
+        Pos3d { x, y, z }
+    }
+
+    /// Adds `x`, `y` and `z` with this position's `x`, `y` and `z` and returns the result in a new [`Pos3d<T>`].
+    pub fn add(&self, x: T, y: T, z: T) -> Pos3d<T>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Pos3d::new(self.x + x, self.y + y, self.z + z)
+    }
+
+    /// Returns a new [`Pos3d<T>`] with the absolute values of `x`, `y` and `z`.
+    pub fn abs(&self) -> Pos3d<T>
+    where
+        T: Neg<Output = T> + PartialOrd<T> + HasZero + Copy,
+    {
+        let abs = |value: T| if value < T::ZERO { -value } else { value };
+        Pos3d::new(abs(self.x), abs(self.y), abs(self.z))
+    }
+
+    /// Returns the maximum of `x`, `y` and `z`.
+    pub fn max(&self) -> T
+    where
+        T: Ord + Copy,
+    {
+        max(max(self.x, self.y), self.z)
+    }
+
+    /// Returns the sum of `x`, `y` and `z`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x`, `y` or `z` can't be converted into type `S`.
+    pub fn sum<S>(&self) -> S
+    where
+        S: Add<S, Output = S> + TryFrom<T>,
+        <S as TryFrom<T>>::Error: Debug,
+        T: Copy,
+    {
+        <S as TryFrom<T>>::try_from(self.x).unwrap()
+            + <S as TryFrom<T>>::try_from(self.y).unwrap()
+            + <S as TryFrom<T>>::try_from(self.z).unwrap()
+    }
+
+    /// Returns the dot product of this position and `other`.
+    pub fn dot(&self, other: Pos3d<T>) -> T
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Copy,
+    {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the cross product of this position and `other`.
+    pub fn cross(&self, other: Pos3d<T>) -> Pos3d<T>
+    where
+        T: Sub<T, Output = T> + Mul<T, Output = T> + Copy,
+    {
+        Pos3d::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Returns the Manhattan distance to `other`, i.e. the sum of the distances along each axis.
+    pub fn manhattan_distance(&self, other: Pos3d<T>) -> T
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + PartialOrd<T> + Copy,
+    {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    /// Returns a new [`Pos3d<T>`] with the lesser of each component in this position and `other`.
+    pub fn component_min(&self, other: Pos3d<T>) -> Pos3d<T>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        Pos3d::new(
+            partial_min(self.x, other.x),
+            partial_min(self.y, other.y),
+            partial_min(self.z, other.z),
+        )
+    }
+
+    /// Returns a new [`Pos3d<T>`] with the greater of each component in this position and `other`.
+    pub fn component_max(&self, other: Pos3d<T>) -> Pos3d<T>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        Pos3d::new(
+            partial_max(self.x, other.x),
+            partial_max(self.y, other.y),
+            partial_max(self.z, other.z),
+        )
+    }
+
+    /// Returns an iterator of the six positions that share a face with this one, i.e. that differ by one along one axis.
+    pub fn neighbors6(&self) -> impl Iterator<Item = Pos3d<T>>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + HasOne + Copy,
+    {
+        let pos = *self;
+        [
+            (Ordering::Less, Ordering::Equal, Ordering::Equal),
+            (Ordering::Greater, Ordering::Equal, Ordering::Equal),
+            (Ordering::Equal, Ordering::Less, Ordering::Equal),
+            (Ordering::Equal, Ordering::Greater, Ordering::Equal),
+            (Ordering::Equal, Ordering::Equal, Ordering::Less),
+            (Ordering::Equal, Ordering::Equal, Ordering::Greater),
+        ]
+        .into_iter()
+        .map(move |(dx, dy, dz)| Pos3d::new(step_towards(pos.x, dx), step_towards(pos.y, dy), step_towards(pos.z, dz)))
+    }
+
+    /// Returns an iterator of the 26 positions that share a face, edge or corner with this one, ordered by `z`, then `y`,
+    /// then `x`.
+    pub fn neighbors26(&self) -> impl Iterator<Item = Pos3d<T>>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + HasOne + Copy,
+    {
+        const SIGNS: [Ordering; 3] = [Ordering::Less, Ordering::Equal, Ordering::Greater];
+        let pos = *self;
+        SIGNS
+            .into_iter()
+            .flat_map(|dz| {
+                SIGNS
+                    .into_iter()
+                    .flat_map(move |dy| SIGNS.into_iter().map(move |dx| (dx, dy, dz)))
+            })
+            .filter(|&delta| delta != (Ordering::Equal, Ordering::Equal, Ordering::Equal))
+            .map(move |(dx, dy, dz)| Pos3d::new(step_towards(pos.x, dx), step_towards(pos.y, dy), step_towards(pos.z, dz)))
+    }
+
+    /// Returns the smallest cuboid containing every position in `positions`, or `None` if `positions` is empty. Same as
+    /// [`Cuboid::bounding_box`].
+    pub fn bounding_box<I>(positions: I) -> Option<Cuboid<T>>
+    where
+        I: IntoIterator<Item = Pos3d<T>>,
+        T: PartialOrd<T> + Copy,
+    {
+        Cuboid::bounding_box(positions)
+    }
+}
+
+impl<T: Display> Display for Pos3d<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Add<T, Output = T>> Add<Pos3d<T>> for Pos3d<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Pos3d<T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Sub<T, Output = T>> Sub<Pos3d<T>> for Pos3d<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Pos3d<T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Mul<T, Output = T> + Copy> Mul<T> for Pos3d<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Div<T, Output = T> + Copy> Div<T> for Pos3d<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+/// A position with `N` dimensions, for when [`Pos2d`] and [`Pos3d`] are not enough.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct PosN<T, const N: usize> {
+    pub coords: [T; N],
+}
+
+impl<T, const N: usize> PosN<T, N> {
+    pub fn new(coords: [T; N]) -> PosN<T, N> {
+        PosN { coords }
+    }
+
+    /// Returns a new [`PosN<T, N>`] with the absolute values of each coordinate.
+    pub fn abs(&self) -> PosN<T, N>
+    where
+        T: Neg<Output = T> + PartialOrd<T> + HasZero + Copy,
+    {
+        PosN::new(self.coords.map(|value| if value < T::ZERO { -value } else { value }))
+    }
+
+    /// Returns the dot product of this position and `other`.
+    pub fn dot(&self, other: PosN<T, N>) -> T
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + HasZero + Copy,
+    {
+        (0..N).fold(T::ZERO, |acc, axis| acc + self.coords[axis] * other.coords[axis])
+    }
+
+    /// Returns the Manhattan distance to `other`, i.e. the sum of the distances along each axis.
+    pub fn manhattan_distance(&self, other: PosN<T, N>) -> T
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + PartialOrd<T> + HasZero + Copy,
+    {
+        (0..N).fold(T::ZERO, |acc, axis| acc + abs_diff(self.coords[axis], other.coords[axis]))
+    }
+
+    /// Returns the Chebyshev distance to `other`, i.e. the greatest of the distances along each axis.
+    pub fn chebyshev_distance(&self, other: PosN<T, N>) -> T
+    where
+        T: Sub<T, Output = T> + PartialOrd<T> + HasZero + Copy,
+    {
+        (0..N).fold(T::ZERO, |acc, axis| {
+            partial_max(acc, abs_diff(self.coords[axis], other.coords[axis]))
+        })
+    }
+
+    /// Returns the Manhattan norm, i.e. the Manhattan distance to the origin.
+    pub fn manhattan_norm(&self) -> T
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + PartialOrd<T> + HasZero + Copy,
+    {
+        self.manhattan_distance(PosN::new([T::ZERO; N]))
+    }
+
+    /// Returns the Chebyshev norm, i.e. the Chebyshev distance to the origin.
+    pub fn chebyshev_norm(&self) -> T
+    where
+        T: Sub<T, Output = T> + PartialOrd<T> + HasZero + Copy,
+    {
+        self.chebyshev_distance(PosN::new([T::ZERO; N]))
+    }
+
+    fn offset(&self, signs: [Ordering; N]) -> PosN<T, N>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + HasOne + Copy,
+    {
+        PosN::new(std::array::from_fn(|axis| step_towards(self.coords[axis], signs[axis])))
+    }
+
+    /// Returns an iterator of the `2 * N` positions that differ by one along one axis, ordered by axis with the lesser
+    /// position first.
+    pub fn orthogonal_neighbors(&self) -> impl Iterator<Item = PosN<T, N>>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + HasOne + Copy,
+    {
+        let pos = *self;
+        (0..N).flat_map(move |axis| {
+            [Ordering::Less, Ordering::Greater].map(|sign| {
+                let mut signs = [Ordering::Equal; N];
+                signs[axis] = sign;
+                pos.offset(signs)
+            })
+        })
+    }
+
+    /// Returns an iterator of the `3^N - 1` positions that differ by at most one along every axis, excluding this position.
+    ///
+    /// The neighbors are ordered like numbers, where the last axis is the most significant one.
+    pub fn neighbors(&self) -> impl Iterator<Item = PosN<T, N>>
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + HasOne + Copy,
+    {
+        const SIGNS: [Ordering; 3] = [Ordering::Less, Ordering::Equal, Ordering::Greater];
+        let pos = *self;
+        let count = 3usize.pow(N as u32);
+        (0..count).filter(move |&index| index != count / 2).map(move |index| {
+            let mut signs = [Ordering::Equal; N];
+            let mut rest = index;
+            for sign in signs.iter_mut() {
+                *sign = SIGNS[rest % 3];
+                rest /= 3;
+            }
+            pos.offset(signs)
+        })
+    }
+}
+
+impl<T: Default, const N: usize> Default for PosN<T, N> {
+    fn default() -> Self {
+        PosN::new(std::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T: Display, const N: usize> Display for PosN<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (axis, value) in self.coords.iter().enumerate() {
+            if axis > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<T, const N: usize> Index<usize> for PosN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.coords[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for PosN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.coords[axis]
+    }
+}
+
+impl<T: Add<T, Output = T> + Copy, const N: usize> Add<PosN<T, N>> for PosN<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: PosN<T, N>) -> Self::Output {
+        PosN::new(std::array::from_fn(|axis| self.coords[axis] + rhs.coords[axis]))
+    }
+}
+
+impl<T: Sub<T, Output = T> + Copy, const N: usize> Sub<PosN<T, N>> for PosN<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: PosN<T, N>) -> Self::Output {
+        PosN::new(std::array::from_fn(|axis| self.coords[axis] - rhs.coords[axis]))
+    }
+}
+
+impl<T: Mul<T, Output = T> + Copy, const N: usize> Mul<T> for PosN<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        PosN::new(self.coords.map(|value| value * rhs))
+    }
+}
+
+impl<T: Div<T, Output = T> + Copy, const N: usize> Div<T> for PosN<T, N> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        PosN::new(self.coords.map(|value| value / rhs))
+    }
+}
+
+impl<T> From<Pos2d<T>> for PosN<T, 2> {
+    fn from(pos: Pos2d<T>) -> Self {
+        PosN::new([pos.x, pos.y])
+    }
+}
+
+impl<T> From<PosN<T, 2>> for Pos2d<T> {
+    fn from(pos: PosN<T, 2>) -> Self {
+        let [x, y] = pos.coords;
+        Pos2d::new(x, y)
+    }
+}
+
+impl<T> From<Pos3d<T>> for PosN<T, 3> {
+    fn from(pos: Pos3d<T>) -> Self {
+        PosN::new([pos.x, pos.y, pos.z])
+    }
+}
+
+impl<T> From<PosN<T, 3>> for Pos3d<T> {
+    fn from(pos: PosN<T, 3>) -> Self {
+        let [x, y, z] = pos.coords;
+        Pos3d::new(x, y, z)
+    }
+}
+
+/// Returns the number of integers in `min..=max`, converted into type `S`, or `None` if it doesn't fit.
+fn checked_len<T, S>(min: T, max: T) -> Option<S>
+where
+    T: Sub<T, Output = T> + PartialOrd<T> + HasZero + HasOne + Copy,
+    S: TryFrom<T> + Checked + HasOne,
+{
+    if min < T::ZERO && max >= T::ZERO {
+        // `max - min` can overflow `T` when the range spans zero, but the parts on either side of zero can't.
+        let below_zero = S::try_from(T::ZERO - T::ONE - min).ok()?;
+        S::try_from(max)
+            .ok()?
+            .checked_add(below_zero)?
+            .checked_add(S::ONE)?
+            .checked_add(S::ONE)
+    } else {
+        S::try_from(max - min).ok()?.checked_add(S::ONE)
+    }
+}
+
+/// Returns the value after `value` in the range `min..=max`, wrapping around to `min` after `max`. The second tuple value is
+/// whether it wrapped around.
+fn next_wrapping<T>(value: T, min: T, max: T) -> (T, bool)
+where
+    T: Add<T, Output = T> + PartialOrd<T> + HasOne,
+{
+    if value < max {
+        (value + T::ONE, false)
+    } else {
+        (min, true)
+    }
+}
+
+/// An axis-aligned rectangle of positions, where both corners are inclusive.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct Rect<T> {
+    pub min: Pos2d<T>,
+    pub max: Pos2d<T>,
+}
+
+impl<T> Rect<T> {
+    /// Creates the smallest rectangle containing both corners `a` and `b`.
+    pub fn new(a: Pos2d<T>, b: Pos2d<T>) -> Rect<T>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        Rect {
+            min: a.component_min(b),
+            max: a.component_max(b),
+        }
+    }
+
+    /// Returns the smallest rectangle containing every position in `positions`, or `None` if `positions` is empty.
+    pub fn bounding_box<I>(positions: I) -> Option<Rect<T>>
+    where
+        I: IntoIterator<Item = Pos2d<T>>,
+        T: PartialOrd<T> + Copy,
+    {
+        let mut positions = positions.into_iter();
+        let first = positions.next()?;
+        Some(positions.fold(Rect::new(first, first), |rect, pos| rect.union(&Rect::new(pos, pos))))
+    }
+
+    /// Returns whether `pos` is within this rectangle.
+    pub fn contains(&self, pos: Pos2d<T>) -> bool
+    where
+        T: PartialOrd<T>,
+    {
+        self.min.x <= pos.x && pos.x <= self.max.x && self.min.y <= pos.y && pos.y <= self.max.y
+    }
+
+    /// Returns the positions within both this rectangle and `other`, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Rect<T>) -> Option<Rect<T>>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        let min = self.min.component_max(other.min);
+        let max = self.max.component_min(other.max);
+        (min.x <= max.x && min.y <= max.y).then_some(Rect { min, max })
+    }
+
+    /// Returns the smallest rectangle containing both this rectangle and `other`.
+    pub fn union(&self, other: &Rect<T>) -> Rect<T>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        Rect {
+            min: self.min.component_min(other.min),
+            max: self.max.component_max(other.max),
+        }
+    }
+
+    /// Returns the number of positions in this rectangle as type `S`, or `None` if it doesn't fit.
+    pub fn area<S>(&self) -> Option<S>
+    where
+        T: Sub<T, Output = T> + PartialOrd<T> + HasZero + HasOne + Copy,
+        S: TryFrom<T> + Checked + HasOne,
+    {
+        checked_len::<T, S>(self.min.x, self.max.x)?.checked_mul(checked_len(self.min.y, self.max.y)?)
+    }
+
+    /// Returns an iterator of every position in this rectangle in row-major order, i.e. `x` changes the fastest.
+    pub fn cells(&self) -> RectCells<T>
+    where
+        T: Copy,
+    {
+        RectCells {
+            rect: *self,
+            next: Some(self.min),
+        }
+    }
+}
+
+/// Iterator of the positions in a [`Rect`], created by [`Rect::cells`].
+pub struct RectCells<T> {
+    rect: Rect<T>,
+    next: Option<Pos2d<T>>,
+}
+
+impl<T> Iterator for RectCells<T>
+where
+    T: Add<T, Output = T> + PartialOrd<T> + HasOne + Copy,
+{
+    type Item = Pos2d<T>;
+
+    fn next(&mut self) -> Option<Pos2d<T>> {
+        let current = self.next?;
+        let (x, wrapped) = next_wrapping(current.x, self.rect.min.x, self.rect.max.x);
+        self.next = if !wrapped {
+            Some(Pos2d::new(x, current.y))
+        } else if current.y < self.rect.max.y {
+            Some(Pos2d::new(x, current.y + T::ONE))
+        } else {
+            None
+        };
+        Some(current)
+    }
+}
+
+/// An axis-aligned cuboid of positions, where both corners are inclusive.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct Cuboid<T> {
+    pub min: Pos3d<T>,
+    pub max: Pos3d<T>,
+}
+
+impl<T> Cuboid<T> {
+    /// Creates the smallest cuboid containing both corners `a` and `b`.
+    pub fn new(a: Pos3d<T>, b: Pos3d<T>) -> Cuboid<T>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        Cuboid {
+            min: a.component_min(b),
+            max: a.component_max(b),
+        }
+    }
+
+    /// Returns the smallest cuboid containing every position in `positions`, or `None` if `positions` is empty.
+    pub fn bounding_box<I>(positions: I) -> Option<Cuboid<T>>
+    where
+        I: IntoIterator<Item = Pos3d<T>>,
+        T: PartialOrd<T> + Copy,
+    {
+        let mut positions = positions.into_iter();
+        let first = positions.next()?;
+        Some(positions.fold(Cuboid::new(first, first), |cuboid, pos| cuboid.union(&Cuboid::new(pos, pos))))
+    }
+
+    /// Returns whether `pos` is within this cuboid.
+    pub fn contains(&self, pos: Pos3d<T>) -> bool
+    where
+        T: PartialOrd<T>,
+    {
+        self.min.x <= pos.x
+            && pos.x <= self.max.x
+            && self.min.y <= pos.y
+            && pos.y <= self.max.y
+            && self.min.z <= pos.z
+            && pos.z <= self.max.z
+    }
+
+    /// Returns the positions within both this cuboid and `other`, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Cuboid<T>) -> Option<Cuboid<T>>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        let min = self.min.component_max(other.min);
+        let max = self.max.component_min(other.max);
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Cuboid { min, max })
+    }
+
+    /// Returns the smallest cuboid containing both this cuboid and `other`.
+    pub fn union(&self, other: &Cuboid<T>) -> Cuboid<T>
+    where
+        T: PartialOrd<T> + Copy,
+    {
+        Cuboid {
+            min: self.min.component_min(other.min),
+            max: self.max.component_max(other.max),
+        }
+    }
+
+    /// Returns the number of positions in this cuboid as type `S`, or `None` if it doesn't fit.
+    pub fn volume<S>(&self) -> Option<S>
+    where
+        T: Sub<T, Output = T> + PartialOrd<T> + HasZero + HasOne + Copy,
+        S: TryFrom<T> + Checked + HasOne,
+    {
+        checked_len::<T, S>(self.min.x, self.max.x)?
+            .checked_mul(checked_len(self.min.y, self.max.y)?)?
+            .checked_mul(checked_len(self.min.z, self.max.z)?)
+    }
+
+    /// Returns an iterator of every position in this cuboid in row-major order, i.e. `x` changes the fastest and `z` the
+    /// slowest.
+    pub fn cells(&self) -> CuboidCells<T>
+    where
+        T: Copy,
+    {
+        CuboidCells {
+            cuboid: *self,
+            next: Some(self.min),
+        }
+    }
+}
+
+/// Iterator of the positions in a [`Cuboid`], created by [`Cuboid::cells`].
+pub struct CuboidCells<T> {
+    cuboid: Cuboid<T>,
+    next: Option<Pos3d<T>>,
+}
+
+impl<T> Iterator for CuboidCells<T>
+where
+    T: Add<T, Output = T> + PartialOrd<T> + HasOne + Copy,
+{
+    type Item = Pos3d<T>;
+
+    fn next(&mut self) -> Option<Pos3d<T>> {
+        let current = self.next?;
+        let (min, max) = (self.cuboid.min, self.cuboid.max);
+        let (x, wrapped) = next_wrapping(current.x, min.x, max.x);
+        self.next = if !wrapped {
+            Some(Pos3d::new(x, current.y, current.z))
+        } else {
+            let (y, wrapped) = next_wrapping(current.y, min.y, max.y);
+            if !wrapped {
+                Some(Pos3d::new(x, y, current.z))
+            } else if current.z < max.z {
+                Some(Pos3d::new(x, y, current.z + T::ONE))
+            } else {
+                None
+            }
+        };
+        Some(current)
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    error::Error,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+/// Error returned when combining two sets whose configurations are incompatible, such as two
+/// [`IntSet`](super::intset::IntSet)s with different ranges.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct IncompatibleSetsError;
+
+pub trait Set<Item> {
+    /// Adds `item`. Nothing happens if `item` already exists.
+    fn add(&mut self, item: Item);
+    /// Removes `item`. Nothing happens if `item` does not exist.
+    fn remove(&mut self, item: Item);
+    /// Clears this set from all items.
+    fn clear(&mut self);
+    /// Returns whether `item` exists.
+    fn contains(&self, item: Item) -> bool;
+    /// Returns the amount of items.
+    fn len(&self) -> usize;
+    /// Returns whether this set has no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns an iterator of the items. Sets that keep their items sorted return them in ascending order.
+    fn items(&self) -> impl Iterator<Item = Item> + '_;
+    /// Removes all items that do not exist in `other`.
+    ///
+    /// Returns an error and leaves `self` unchanged if `other` has an incompatible configuration to `self`.
+    fn intersect(&mut self, other: &Self) -> Result<(), IncompatibleSetsError>;
+    /// Adds all items that exist in `other`.
+    ///
+    /// Returns an error and leaves `self` unchanged if `other` has an incompatible configuration to `self`. Named so that
+    /// the inherent `union` of std's sets, which returns an iterator instead, doesn't shadow it.
+    fn union_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError>;
+    /// Removes all items that exist in `other`.
+    ///
+    /// Returns an error and leaves `self` unchanged if `other` has an incompatible configuration to `self`. Named like
+    /// [`Set::union_with`] for the same reason.
+    fn difference_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError>;
+}
+
+impl<T: Eq + Hash + Clone> Set<T> for HashSet<T> {
+    fn add(&mut self, item: T) {
+        self.insert(item);
+    }
+
+    fn remove(&mut self, item: T) {
+        HashSet::remove(self, &item);
+    }
+
+    fn clear(&mut self) {
+        HashSet::clear(self);
+    }
+
+    fn contains(&self, item: T) -> bool {
+        HashSet::contains(self, &item)
+    }
+
+    fn len(&self) -> usize {
+        HashSet::len(self)
+    }
+
+    fn items(&self) -> impl Iterator<Item = T> + '_ {
+        self.iter().cloned()
+    }
+
+    fn intersect(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.retain(|item| other.contains(item));
+        Ok(())
+    }
+
+    fn union_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.extend(other.iter().cloned());
+        Ok(())
+    }
+
+    fn difference_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.retain(|item| !other.contains(item));
+        Ok(())
+    }
+}
+
+impl<T: Ord + Clone> Set<T> for BTreeSet<T> {
+    fn add(&mut self, item: T) {
+        self.insert(item);
+    }
+
+    fn remove(&mut self, item: T) {
+        BTreeSet::remove(self, &item);
+    }
+
+    fn clear(&mut self) {
+        BTreeSet::clear(self);
+    }
+
+    fn contains(&self, item: T) -> bool {
+        BTreeSet::contains(self, &item)
+    }
+
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn items(&self) -> impl Iterator<Item = T> + '_ {
+        self.iter().cloned()
+    }
+
+    fn intersect(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.retain(|item| other.contains(item));
+        Ok(())
+    }
+
+    fn union_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.extend(other.iter().cloned());
+        Ok(())
+    }
+
+    fn difference_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.retain(|item| !other.contains(item));
+        Ok(())
+    }
+}
+
+impl Display for IncompatibleSetsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sets have incompatible configurations")
+    }
+}
+
+impl Error for IncompatibleSetsError {}
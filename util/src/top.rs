@@ -3,7 +3,7 @@ use std::cmp::Ordering;
 pub trait TopIter {
     /// Collects a Vec of the highest valued elements in the iterator.
     ///
-    /// You can decide the number of returned elements by specifying `num_elems`. The resulting Vec is sorted in descending
+    /// You can decide the number of returned elements by specifying `num_elems`. The resulting Vec is sorted in ascending
     /// order.
     fn top<T>(self, num_elems: usize) -> Vec<T>
     where
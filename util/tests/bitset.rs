@@ -0,0 +1,55 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use aoc_util::{bitset::BitSet, set::Set};
+use common::{Rng, CASES};
+
+#[test]
+fn add_remove_contains() {
+    let mut set = BitSet::<2>::new();
+    assert_eq!(BitSet::<2>::CAPACITY, 128);
+    assert!(set.is_empty());
+    set.add(0);
+    set.add(64);
+    set.add(127);
+    set.add(64);
+    assert!(set.contains(0) && set.contains(64) && set.contains(127));
+    assert!(!set.contains(1) && !set.contains(128));
+    assert_eq!(set.count(), 3);
+    assert_eq!(set.iter().collect::<Vec<usize>>(), [0, 64, 127]);
+    set.remove(64);
+    set.remove(1000);
+    assert_eq!(set.count(), 2);
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn add_out_of_range() {
+    BitSet::<1>::new().add(64);
+}
+
+#[test]
+fn matches_btreeset() {
+    let mut rng = Rng::new(12);
+    for _ in 0..CASES {
+        let mut a = BitSet::<3>::new();
+        let mut b = BitSet::<3>::new();
+        let mut model_a = BTreeSet::new();
+        let mut model_b = BTreeSet::new();
+        for _ in 0..rng.range(0, 80) {
+            let item = rng.range(0, 192) as usize;
+            a.add(item);
+            model_a.insert(item);
+            let item = rng.range(0, 192) as usize;
+            b.add(item);
+            model_b.insert(item);
+        }
+        assert!(a.iter().eq(model_a.iter().copied()));
+        a.intersect(&b).unwrap();
+        assert!(a.iter().eq(model_a.intersection(&model_b).copied()));
+        assert_eq!(a.count(), model_a.intersection(&model_b).count());
+    }
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use aoc_util::pos::{Cuboid, Pos2d, Pos3d, Rect};
+use common::{Rng, CASES};
+
+#[test]
+fn rect_contains_and_cells() {
+    let rect = Rect::new(Pos2d::new(3, 2), Pos2d::new(1, 3));
+    assert_eq!(rect.min, Pos2d::new(1, 2));
+    assert_eq!(rect.max, Pos2d::new(3, 3));
+    assert!(rect.contains(Pos2d::new(2, 2)));
+    assert!(!rect.contains(Pos2d::new(0, 2)));
+    let cells: Vec<Pos2d<i32>> = rect.cells().collect();
+    assert_eq!(
+        cells,
+        [(1, 2), (2, 2), (3, 2), (1, 3), (2, 3), (3, 3)].map(|(x, y)| Pos2d::new(x, y))
+    );
+    assert_eq!(rect.area::<u32>(), Some(6));
+}
+
+#[test]
+fn rect_intersection_and_union() {
+    let a = Rect::new(Pos2d::new(0, 0), Pos2d::new(4, 4));
+    let b = Rect::new(Pos2d::new(3, -2), Pos2d::new(6, 1));
+    assert_eq!(a.intersection(&b), Some(Rect::new(Pos2d::new(3, 0), Pos2d::new(4, 1))));
+    assert_eq!(a.union(&b), Rect::new(Pos2d::new(0, -2), Pos2d::new(6, 4)));
+    let c = Rect::new(Pos2d::new(5, 5), Pos2d::new(6, 6));
+    assert_eq!(a.intersection(&c), None);
+}
+
+#[test]
+fn rect_area_overflow() {
+    let rect = Rect::new(Pos2d::new(0u32, 0), Pos2d::new(u32::MAX - 1, 1));
+    assert_eq!(rect.area::<u32>(), None);
+    assert_eq!(rect.area::<u64>(), Some(u32::MAX as u64 * 2));
+    let negative = Rect::new(Pos2d::new(-5i64, 0), Pos2d::new(5, 0));
+    assert_eq!(negative.area::<u8>(), Some(11));
+}
+
+#[test]
+fn wide_signed_bounds() {
+    let rect = Rect::new(Pos2d::new(-100i8, -128), Pos2d::new(100, 127));
+    assert_eq!(rect.area::<u32>(), Some(201 * 256));
+    assert_eq!(rect.area::<u8>(), None);
+    let full = Cuboid::new(Pos3d::new(i32::MIN, i32::MIN, 0), Pos3d::new(i32::MAX, i32::MAX, 0));
+    assert_eq!(full.volume::<u64>(), None);
+    assert_eq!(full.volume::<u128>(), Some(1 << 64));
+    let full = Cuboid::new(Pos3d::new(i32::MIN, 0, 0), Pos3d::new(i32::MAX, 1, 0));
+    assert_eq!(full.volume::<u64>(), Some(1 << 33));
+}
+
+#[test]
+fn rect_cells_at_type_limits() {
+    let rect = Rect::new(Pos2d::new(254u8, 254), Pos2d::new(255, 255));
+    assert_eq!(rect.cells().count(), 4);
+}
+
+#[test]
+fn rect_bounding_box() {
+    let positions = [Pos2d::new(1, 5), Pos2d::new(-2, 3), Pos2d::new(0, 7)];
+    assert_eq!(
+        Rect::bounding_box(positions),
+        Some(Rect::new(Pos2d::new(-2, 3), Pos2d::new(1, 7)))
+    );
+    assert_eq!(Rect::<i32>::bounding_box([]), None);
+}
+
+#[test]
+fn cuboid_operations() {
+    let a = Cuboid::new(Pos3d::new(0, 0, 0), Pos3d::new(2, 2, 2));
+    let b = Cuboid::new(Pos3d::new(1, 1, 1), Pos3d::new(3, 3, 3));
+    assert!(a.contains(Pos3d::new(2, 0, 1)));
+    assert!(!a.contains(Pos3d::new(2, 0, 3)));
+    assert_eq!(
+        a.intersection(&b),
+        Some(Cuboid::new(Pos3d::new(1, 1, 1), Pos3d::new(2, 2, 2)))
+    );
+    assert_eq!(a.union(&b), Cuboid::new(Pos3d::new(0, 0, 0), Pos3d::new(3, 3, 3)));
+    assert_eq!(a.volume::<u64>(), Some(27));
+    assert_eq!(Cuboid::new(Pos3d::new(0, 0, 0), Pos3d::new(99, 99, 99)).volume::<u16>(), None);
+    let cells: Vec<Pos3d<i32>> = Cuboid::new(Pos3d::new(0, 0, 0), Pos3d::new(1, 1, 1)).cells().collect();
+    assert_eq!(cells[1], Pos3d::new(1, 0, 0));
+    assert_eq!(cells[2], Pos3d::new(0, 1, 0));
+    assert_eq!(cells[4], Pos3d::new(0, 0, 1));
+    assert_eq!(cells.len(), 8);
+    assert_eq!(
+        Cuboid::bounding_box(cells),
+        Some(Cuboid::new(Pos3d::new(0, 0, 0), Pos3d::new(1, 1, 1)))
+    );
+    assert_eq!(Cuboid::<i32>::bounding_box([]), None);
+}
+
+#[test]
+fn rect_cells_match_area_and_intersection() {
+    let mut rng = Rng::new(10);
+    for _ in 0..CASES {
+        let random_rect = |rng: &mut Rng| {
+            Rect::new(
+                Pos2d::new(rng.range(-10, 10), rng.range(-10, 10)),
+                Pos2d::new(rng.range(-10, 10), rng.range(-10, 10)),
+            )
+        };
+        let a = random_rect(&mut rng);
+        let b = random_rect(&mut rng);
+        assert_eq!(a.cells().count(), a.area::<usize>().unwrap());
+        assert!(a.cells().all(|pos| a.contains(pos)));
+        let both = a.cells().filter(|&pos| b.contains(pos)).count();
+        assert_eq!(both, a.intersection(&b).map_or(0, |rect| rect.area::<usize>().unwrap()));
+        let union = a.union(&b);
+        assert!(a.cells().chain(b.cells()).all(|pos| union.contains(pos)));
+    }
+}
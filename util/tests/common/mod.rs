@@ -0,0 +1,26 @@
+// Not every test binary uses every helper.
+#![allow(dead_code)]
+
+/// Small xorshift generator for property tests, seeded so that failures are reproducible.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a number in `min..max`.
+    pub fn range(&mut self, min: i64, max: i64) -> i64 {
+        min + (self.next_u64() % (max - min) as u64) as i64
+    }
+}
+
+/// Number of cases each property test checks.
+pub const CASES: u64 = 500;
@@ -0,0 +1,82 @@
+mod common;
+
+use aoc_util::cycle::{self, Cycle};
+use common::{Rng, CASES};
+
+/// Returns a random function on a small range of numbers, whose sequences are all eventually periodic.
+fn random_function(rng: &mut Rng) -> Vec<usize> {
+    let size = rng.range(1, 60);
+    (0..size).map(|_| rng.range(0, size) as usize).collect()
+}
+
+/// Finds the cycle by remembering every element in a list, the slowest way possible.
+fn naive_cycle(function: &[usize], start: usize) -> Cycle {
+    let mut elements = vec![start];
+    loop {
+        let next = function[*elements.last().unwrap()];
+        if let Some(lead_in) = elements.iter().position(|&element| element == next) {
+            return Cycle {
+                lead_in,
+                length: elements.len() - lead_in,
+            };
+        }
+        elements.push(next);
+    }
+}
+
+#[test]
+fn finds_cycle() {
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+    let function = [1, 2, 3, 4, 2];
+    let expected = Cycle { lead_in: 2, length: 3 };
+    assert_eq!(cycle::brent(0, |&x| function[x]), expected);
+    assert_eq!(cycle::floyd(0, |&x| function[x]), expected);
+    let (found, elements) = cycle::with_memo(0, |&x| function[x]);
+    assert_eq!(found, expected);
+    assert_eq!(elements, [0, 1, 2, 3, 4]);
+
+    assert_eq!(cycle::brent(7, |&x| x), Cycle { lead_in: 0, length: 1 });
+    assert_eq!(cycle::floyd(7, |&x| x), Cycle { lead_in: 0, length: 1 });
+}
+
+#[test]
+fn reduce_and_positions() {
+    let function = [1, 2, 3, 4, 2];
+    let found = Cycle { lead_in: 2, length: 3 };
+    assert_eq!(found.reduce(1), 1);
+    assert_eq!(found.reduce(5), 2);
+    assert_eq!(found.reduce(1_000_000_000_000), 4);
+    assert_eq!(found.nth(0, |&x| function[x], 1_000_000_000_000), 4);
+    assert_eq!(found.positions(0, |&x| function[x], |&x| x % 2 == 1), [1, 3]);
+}
+
+#[test]
+fn algorithms_agree() {
+    let mut rng = Rng::new(21);
+    for _ in 0..CASES {
+        let function = random_function(&mut rng);
+        let start = rng.range(0, function.len() as i64) as usize;
+        let expected = naive_cycle(&function, start);
+        assert_eq!(cycle::brent(start, |&x| function[x]), expected);
+        assert_eq!(cycle::floyd(start, |&x| function[x]), expected);
+        let (found, elements) = cycle::with_memo(start, |&x| function[x]);
+        assert_eq!(found, expected);
+        assert_eq!(elements.len(), expected.lead_in + expected.length);
+    }
+}
+
+#[test]
+fn nth_matches_walking() {
+    let mut rng = Rng::new(22);
+    for _ in 0..CASES {
+        let function = random_function(&mut rng);
+        let start = rng.range(0, function.len() as i64) as usize;
+        let n = rng.range(0, 500) as u64;
+        let walked = (0..n).fold(start, |x, _| function[x]);
+        assert_eq!(cycle::nth(start, |&x| function[x], n), walked);
+        assert_eq!(naive_cycle(&function, start).nth(start, |&x| function[x], n), walked);
+    }
+    // Far beyond anything that could be walked, in a cycle of length 7 after a lead-in of 3.
+    let step = |&x: &u64| if x < 3 { x + 1 } else { 3 + (x - 2) % 7 };
+    assert_eq!(cycle::nth(0, step, 3 + 7 * 1_000_000_000_000_000 + 4), 7);
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use aoc_util::graph::{CyclicGraphError, Graph};
+use common::{Rng, CASES};
+
+/// Builds a graph from `(from, to, weight)` edges between nodes named by their index.
+fn graph_of(len: usize, edges: &[(usize, usize, u64)]) -> Graph {
+    let mut graph = Graph::new();
+    for node in 0..len {
+        graph.add_node(&node.to_string());
+    }
+    for &(from, to, weight) in edges {
+        graph.add_edge(from, to, weight, ());
+    }
+    graph
+}
+
+fn random_edges(rng: &mut Rng, len: usize) -> Vec<(usize, usize, u64)> {
+    (0..rng.range(0, 3 * len as i64))
+        .map(|_| {
+            (
+                rng.range(0, len as i64) as usize,
+                rng.range(0, len as i64) as usize,
+                rng.range(0, 20) as u64,
+            )
+        })
+        .collect()
+}
+
+/// Returns the lowest weight of a path between every pair of nodes, using the Floyd–Warshall algorithm.
+fn all_distances(len: usize, edges: &[(usize, usize, u64)], weighted: bool) -> Vec<Vec<Option<u64>>> {
+    let mut distances = vec![vec![None; len]; len];
+    for (node, row) in distances.iter_mut().enumerate() {
+        row[node] = Some(0);
+    }
+    for &(from, to, weight) in edges {
+        let weight = if weighted { weight } else { 1 };
+        distances[from][to] = Some(distances[from][to].map_or(weight, |best: u64| best.min(weight)));
+    }
+    for via in 0..len {
+        for from in 0..len {
+            for to in 0..len {
+                if let (Some(first), Some(second)) = (distances[from][via], distances[via][to]) {
+                    distances[from][to] = Some(distances[from][to].map_or(first + second, |best| best.min(first + second)));
+                }
+            }
+        }
+    }
+    distances
+}
+
+/// Returns the total weight of `path`, checking that every step follows an edge.
+fn path_weight(graph: &Graph, path: &[usize], weighted: bool) -> u64 {
+    path.windows(2)
+        .map(|pair| {
+            let edges = graph.edges(pair[0]).iter().filter(|edge| edge.to == pair[1]);
+            edges
+                .map(|edge| if weighted { edge.weight } else { 1 })
+                .min()
+                .expect("paths should follow edges")
+        })
+        .sum()
+}
+
+#[test]
+fn nodes_by_name() {
+    let mut graph: Graph<char> = Graph::new();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    assert_eq!(graph.add_node("a"), a);
+    graph.add_edge(a, b, 3, 'x');
+    assert_eq!(graph.node("b"), Some(b));
+    assert_eq!(graph.node("c"), None);
+    assert_eq!(graph.name(b), "b");
+    assert_eq!(graph.len(), 2);
+    assert_eq!(graph.edges(a)[0].label, 'x');
+    assert_eq!(graph.neighbors(a).collect::<Vec<_>>(), [b]);
+    assert_eq!(graph.nodes().collect::<Vec<_>>(), [(a, "a"), (b, "b")]);
+}
+
+#[test]
+fn searches() {
+    // 0 -> 1 -> 3, 0 -> 2 -> 3 with a lighter path through 2, and 4 unreachable.
+    let graph = graph_of(5, &[(0, 1, 1), (1, 3, 10), (0, 2, 2), (2, 3, 2), (4, 0, 1)]);
+    let bfs = graph.bfs([0]);
+    assert_eq!(bfs.distance(3), Some(2));
+    assert_eq!(bfs.path_to(3), Some(vec![0, 1, 3]));
+    assert_eq!(bfs.path_to(4), None);
+
+    let dijkstra = graph.dijkstra([0]);
+    assert_eq!(dijkstra.distance(3), Some(4));
+    assert_eq!(dijkstra.path_to(3), Some(vec![0, 2, 3]));
+    assert_eq!(graph.a_star(0, 3, |_| 0), Some((4, vec![0, 2, 3])));
+    assert_eq!(graph.a_star(0, 4, |_| 0), None);
+
+    assert_eq!(graph.dfs(0), [0, 1, 3, 2]);
+    assert_eq!(graph.bfs([1, 2]).distance(3), Some(1));
+}
+
+#[test]
+fn shortest_paths_match_floyd_warshall() {
+    let mut rng = Rng::new(31);
+    for _ in 0..CASES {
+        let len = rng.range(1, 12) as usize;
+        let edges = random_edges(&mut rng, len);
+        let graph = graph_of(len, &edges);
+        let unweighted = all_distances(len, &edges, false);
+        let weighted = all_distances(len, &edges, true);
+        let start = rng.range(0, len as i64) as usize;
+        let bfs = graph.bfs([start]);
+        let dijkstra = graph.dijkstra([start]);
+        for goal in 0..len {
+            assert_eq!(bfs.distance(goal), unweighted[start][goal]);
+            assert_eq!(dijkstra.distance(goal), weighted[start][goal]);
+            if let Some(distance) = weighted[start][goal] {
+                let path = dijkstra.path_to(goal).unwrap();
+                assert_eq!((path[0], *path.last().unwrap()), (start, goal));
+                assert_eq!(path_weight(&graph, &path, true), distance);
+                assert_eq!(
+                    path_weight(&graph, &bfs.path_to(goal).unwrap(), false),
+                    unweighted[start][goal].unwrap()
+                );
+            }
+            // Half of the true remaining distance never overestimates, so A* should still find the lightest path.
+            let heuristic = |node: usize| weighted[node][goal].map_or(0, |distance| distance / 2);
+            let a_star = graph.a_star(start, goal, heuristic);
+            assert_eq!(a_star.as_ref().map(|(distance, _)| *distance), weighted[start][goal]);
+            if let Some((distance, path)) = a_star {
+                assert_eq!(path_weight(&graph, &path, true), distance);
+            }
+        }
+        let reachable = unweighted[start].iter().filter(|distance| distance.is_some()).count();
+        assert_eq!(graph.dfs(start).len(), reachable);
+    }
+}
+
+#[test]
+fn topological_sort() {
+    let graph = graph_of(4, &[(2, 0, 1), (0, 1, 1), (2, 3, 1), (3, 1, 1)]);
+    let order = graph.topological_sort().unwrap();
+    let position = |node| order.iter().position(|&other| other == node).unwrap();
+    assert!(position(2) < position(0) && position(0) < position(1) && position(3) < position(1));
+
+    let cyclic = graph_of(3, &[(0, 1, 1), (1, 2, 1), (2, 1, 1)]);
+    assert_eq!(cyclic.topological_sort(), Err(CyclicGraphError));
+}
+
+#[test]
+fn components_match_reachability() {
+    let mut rng = Rng::new(32);
+    for _ in 0..CASES {
+        let len = rng.range(1, 12) as usize;
+        let edges = random_edges(&mut rng, len);
+        let graph = graph_of(len, &edges);
+        let distances = all_distances(len, &edges, false);
+        let components = graph.strongly_connected_components();
+
+        let mut component_of = vec![usize::MAX; len];
+        for (index, component) in components.iter().enumerate() {
+            for &node in component {
+                assert_eq!(component_of[node], usize::MAX, "nodes should be in one component");
+                component_of[node] = index;
+            }
+        }
+        for a in 0..len {
+            for b in 0..len {
+                let mutual = distances[a][b].is_some() && distances[b][a].is_some();
+                assert_eq!(component_of[a] == component_of[b], mutual);
+                // Edges between components lead to earlier ones.
+                if distances[a][b].is_some() {
+                    assert!(component_of[b] <= component_of[a]);
+                }
+            }
+        }
+
+        let acyclic = components.len() == len && edges.iter().all(|(from, to, _)| from != to);
+        assert_eq!(graph.topological_sort().is_ok(), acyclic);
+    }
+}
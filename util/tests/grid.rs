@@ -0,0 +1,70 @@
+use aoc_util::{
+    grid::{Grid, ParseGridError},
+    pos::Pos2d,
+};
+
+const TEXT: &str = "abc\ndef\n";
+
+#[test]
+fn parse_and_display() {
+    let grid: Grid<char> = TEXT.parse().unwrap();
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid.to_string(), TEXT);
+
+    let digits = Grid::parse("12\n34", |ch| ch.to_digit(10).unwrap()).unwrap();
+    assert_eq!(*digits.get(Pos2d::new(1, 1)), 4);
+}
+
+#[test]
+fn parse_ragged() {
+    assert_eq!(
+        "ab\nc\n".parse::<Grid<char>>(),
+        Err(ParseGridError {
+            row: 1,
+            width: 1,
+            expected_width: 2
+        })
+    );
+}
+
+#[test]
+fn get_and_set() {
+    let mut grid = Grid::new(3, 2, 0);
+    grid.set(Pos2d::new(2, 1), 5);
+    assert_eq!(*grid.get(Pos2d::new(2usize, 1)), 5);
+    assert_eq!(grid.try_get(Pos2d::new(-1, 0)), None);
+    assert_eq!(grid.try_get(Pos2d::new(3, 0)), None);
+    assert_eq!(grid.try_get(Pos2d::new(0i16, 2)), None);
+    assert!(grid.in_bounds(Pos2d::new(0, 0)));
+    assert!(!grid.in_bounds(Pos2d::new(0, -1)));
+}
+
+#[test]
+#[should_panic]
+fn set_out_of_bounds() {
+    Grid::new(1, 1, 0).set(Pos2d::new(1, 0), 1);
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid: Grid<char> = TEXT.parse().unwrap();
+    let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    assert_eq!(rows, ["abc", "def"]);
+    let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+    assert_eq!(columns, ["ad", "be", "cf"]);
+    let positions: Vec<Pos2d<usize>> = grid.iter().map(|(pos, _)| pos).collect();
+    assert_eq!(positions[4], Pos2d::new(1, 1));
+}
+
+#[test]
+fn neighbors() {
+    let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+    let corner: String = grid.neighbors4(Pos2d::new(0, 0)).map(|(_, &ch)| ch).collect();
+    assert_eq!(corner, "bd");
+    let center: String = grid.neighbors4(Pos2d::new(1, 1)).map(|(_, &ch)| ch).collect();
+    assert_eq!(center, "bfhd");
+    let all: String = grid.neighbors8(Pos2d::new(1, 1)).map(|(_, &ch)| ch).collect();
+    assert_eq!(all, "bcfihgda");
+    assert_eq!(grid.neighbors8(Pos2d::new(2, 2)).count(), 3);
+}
@@ -0,0 +1,18 @@
+use aoc_util::{array::AsArray, try_index::TryIndex};
+
+#[test]
+fn try_index_bounds() {
+    let items = vec![1, 2, 3];
+    assert_eq!(items.try_index(-1), None);
+    assert_eq!(items.try_index(0), Some(&1));
+    assert_eq!(items.try_index(2), Some(&3));
+    assert_eq!(items.try_index(3), None);
+}
+
+#[test]
+fn as_array_pads_and_truncates() {
+    let short: [char; 4] = "ab".as_array();
+    assert_eq!(short, ['a', 'b', '\0', '\0']);
+    let long: [char; 2] = "abc".as_array();
+    assert_eq!(long, ['a', 'b']);
+}
@@ -0,0 +1,32 @@
+use aoc_util::intern::Interner;
+
+#[test]
+fn indices_are_dense() {
+    let mut interner = Interner::new();
+    assert!(interner.is_empty());
+    assert_eq!(interner.intern("AAA"), 0);
+    assert_eq!(interner.intern("a much longer name"), 1);
+    assert_eq!(interner.intern("AAA"), 0);
+    assert_eq!(interner.intern(""), 2);
+    assert_eq!(interner.len(), 3);
+    assert_eq!(
+        interner.iter().collect::<Vec<_>>(),
+        [(0, "AAA"), (1, "a much longer name"), (2, "")]
+    );
+}
+
+#[test]
+fn lookups() {
+    let mut interner = Interner::new();
+    let names = ["left", "right", "up", "down"];
+    let indices = names.map(|name| interner.intern(name));
+    for (name, index) in names.into_iter().zip(indices) {
+        assert_eq!(interner.get(name), Some(index));
+        assert_eq!(interner.name(index), name);
+    }
+    assert_eq!(interner.get("sideways"), None);
+
+    interner.clear();
+    assert_eq!(interner.get("left"), None);
+    assert_eq!(interner.intern("up"), 0);
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use aoc_util::interval::IntervalSet;
+use common::{Rng, CASES};
+
+#[test]
+fn insert_merges() {
+    let mut set = IntervalSet::new();
+    set.insert(5..10);
+    set.insert(0..2);
+    set.insert(12..15);
+    assert_eq!(set.ranges(), [0..2, 5..10, 12..15]);
+    set.insert(10..12);
+    assert_eq!(set.ranges(), [0..2, 5..15]);
+    set.insert(1..6);
+    assert_eq!(set, IntervalSet::from(0..15));
+    set.insert(20..20);
+    assert_eq!(set, IntervalSet::from(0..15));
+    assert_eq!(set.len(), 15);
+}
+
+#[test]
+fn remove_splits() {
+    let mut set = IntervalSet::from(0..10);
+    set.remove(3..5);
+    assert_eq!(set.ranges(), [0..3, 5..10]);
+    set.remove(-5..1);
+    assert_eq!(set.ranges(), [1..3, 5..10]);
+    set.remove(2..8);
+    assert_eq!(set.ranges(), [1..2, 8..10]);
+    set.remove(0..20);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn queries() {
+    let set: IntervalSet<i64> = [0..3, 10..20].into_iter().collect();
+    assert!(set.contains(0) && set.contains(2) && set.contains(19));
+    assert!(!set.contains(3) && !set.contains(-1) && !set.contains(20));
+    assert!(set.contains_range(12..20));
+    assert!(set.contains_range(5..5));
+    assert!(!set.contains_range(2..11));
+    assert_eq!(set.min(), Some(0));
+    assert_eq!(set.end(), Some(20));
+    assert_eq!(set.translated(-5).ranges(), [-5..-2, 5..15]);
+}
+
+#[test]
+fn set_algebra() {
+    let a: IntervalSet<i32> = [0..5, 10..15].into_iter().collect();
+    let b = IntervalSet::from(3..12);
+    assert_eq!(a.intersection(&b).ranges(), [3..5, 10..12]);
+    assert_eq!(a.union(&b), IntervalSet::from(0..15));
+    assert_eq!(a.difference(&b).ranges(), [0..3, 12..15]);
+    assert_eq!(b.difference(&a), IntervalSet::from(5..10));
+}
+
+fn random_set(rng: &mut Rng) -> (IntervalSet<i64>, BTreeSet<i64>) {
+    let mut set = IntervalSet::new();
+    let mut model = BTreeSet::new();
+    for _ in 0..rng.range(0, 8) {
+        let start = rng.range(-50, 50);
+        let end = start + rng.range(0, 15);
+        if rng.range(0, 3) == 0 {
+            set.remove(start..end);
+            (start..end).for_each(|value| {
+                model.remove(&value);
+            });
+        } else {
+            set.insert(start..end);
+            model.extend(start..end);
+        }
+    }
+    (set, model)
+}
+
+fn is_canonical(set: &IntervalSet<i64>) -> bool {
+    set.ranges().iter().all(|range| range.start < range.end) && set.ranges().windows(2).all(|pair| pair[0].end < pair[1].start)
+}
+
+#[test]
+fn matches_btreeset() {
+    let mut rng = Rng::new(13);
+    for _ in 0..CASES {
+        let (a, model_a) = random_set(&mut rng);
+        let (b, model_b) = random_set(&mut rng);
+        assert!(is_canonical(&a));
+        assert_eq!(a.len(), model_a.len() as i64);
+        assert!((-70..70).all(|value| a.contains(value) == model_a.contains(&value)));
+
+        let intersection = a.intersection(&b);
+        let union = a.union(&b);
+        let difference = a.difference(&b);
+        for (set, model) in [
+            (
+                &intersection,
+                model_a.intersection(&model_b).copied().collect::<BTreeSet<i64>>(),
+            ),
+            (&union, model_a.union(&model_b).copied().collect()),
+            (&difference, model_a.difference(&model_b).copied().collect()),
+        ] {
+            assert!(is_canonical(set));
+            let values: BTreeSet<i64> = set.ranges().iter().flat_map(|range| range.clone()).collect();
+            assert_eq!(values, model);
+        }
+    }
+}
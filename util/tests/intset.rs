@@ -0,0 +1,194 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use aoc_util::{
+    intset::{IntSet, OutOfRangeError},
+    set::{IncompatibleSetsError, Set},
+};
+use common::{Rng, CASES};
+
+#[test]
+fn add_remove_contains() {
+    let mut set = IntSet::new(-10, 100);
+    assert_eq!(set.get_min(), -10);
+    assert_eq!(set.get_max(), 100);
+    set.add(-10);
+    set.add(5);
+    set.add(99);
+    set.add(5);
+    assert!(set.contains(-10) && set.contains(5) && set.contains(99));
+    assert!(!set.contains(6));
+    assert_eq!(set.count(), 3);
+    set.remove(5);
+    set.remove(6);
+    assert!(!set.contains(5));
+    assert_eq!(set.count(), 2);
+    set.clear();
+    assert_eq!(set.count(), 0);
+}
+
+#[test]
+fn out_of_range_is_never_contained() {
+    let mut set = IntSet::new(0, 10);
+    set.remove(-1);
+    set.remove(10);
+    assert!(!set.contains(-1));
+    assert!(!set.contains(10));
+}
+
+#[test]
+fn try_add_out_of_range() {
+    let mut set = IntSet::new(0, 100);
+    assert_eq!(set.try_add(99), Ok(()));
+    assert_eq!(
+        set.try_add(100),
+        Err(OutOfRangeError {
+            item: 100,
+            min: 0,
+            max: 100
+        })
+    );
+    assert!(set.try_add(-1).is_err());
+    assert_eq!(set.count(), 1);
+}
+
+#[test]
+#[should_panic]
+fn add_out_of_range() {
+    // The bit field has room for 128 numbers, but 120 is still out of range.
+    IntSet::new(0, 100).add(120);
+}
+
+#[test]
+fn iteration_and_queries() {
+    let set: IntSet = [70, -3, 5, 64, 200].into_iter().collect();
+    assert_eq!(set.get_min(), -3);
+    assert_eq!(set.get_max(), 201);
+    assert_eq!(set.iter().collect::<Vec<isize>>(), [-3, 5, 64, 70, 200]);
+    assert_eq!(set.first(), Some(-3));
+    assert_eq!(set.last(), Some(200));
+    assert_eq!(set.next_after(-10), Some(-3));
+    assert_eq!(set.next_after(5), Some(64));
+    assert_eq!(set.next_after(6), Some(64));
+    assert_eq!(set.next_after(70), Some(200));
+    assert_eq!(set.next_after(200), None);
+
+    let empty = IntSet::new(0, 10);
+    assert!(empty.is_empty());
+    assert_eq!(empty.first(), None);
+    assert_eq!(empty.last(), None);
+    assert_eq!(empty.next_after(3), None);
+    assert_eq!(IntSet::from_iter([]).count(), 0);
+}
+
+#[test]
+fn set_algebra() {
+    let mut a = IntSet::new(0, 100);
+    let mut b = IntSet::new(0, 100);
+    a.extend([1, 2, 3, 80]);
+    b.extend([3, 4, 80]);
+
+    let mut union = a.clone();
+    union.union(&b).unwrap();
+    assert_eq!(union.iter().collect::<Vec<isize>>(), [1, 2, 3, 4, 80]);
+    let mut difference = a.clone();
+    difference.difference(&b).unwrap();
+    assert_eq!(difference.iter().collect::<Vec<isize>>(), [1, 2]);
+    let mut symmetric_difference = a.clone();
+    symmetric_difference.symmetric_difference(&b).unwrap();
+    assert_eq!(symmetric_difference.iter().collect::<Vec<isize>>(), [1, 2, 4]);
+
+    assert!(difference.is_subset(&a));
+    assert!(a.is_superset(&difference));
+    assert!(!a.is_subset(&b));
+    assert!(difference.is_disjoint(&b));
+    assert!(!a.is_disjoint(&b));
+
+    let other_range: IntSet = [2, 3].into_iter().collect();
+    assert!(other_range.is_subset(&a));
+    assert!(!other_range.is_disjoint(&a));
+}
+
+#[test]
+fn intersect() {
+    let mut a = IntSet::new(0, 200);
+    let mut b = IntSet::new(0, 200);
+    [1, 64, 65, 150].iter().for_each(|&x| a.add(x));
+    [64, 150, 199].iter().for_each(|&x| b.add(x));
+    a.intersect(&b).unwrap();
+    assert_eq!(a.count(), 2);
+    assert!(a.contains(64) && a.contains(150));
+}
+
+#[test]
+fn incompatible_ranges() {
+    let mut a = IntSet::new(0, 10);
+    a.add(3);
+    let b = IntSet::new(0, 20);
+    assert_eq!(a.intersect(&b), Err(IncompatibleSetsError));
+    assert_eq!(a.union(&b), Err(IncompatibleSetsError));
+    assert_eq!(a.difference(&b), Err(IncompatibleSetsError));
+    assert_eq!(a.symmetric_difference(&b), Err(IncompatibleSetsError));
+    assert!(a.contains(3));
+}
+
+#[test]
+fn matches_btreeset() {
+    let mut rng = Rng::new(5);
+    for _ in 0..CASES {
+        let (min, max) = (rng.range(-300, 0), rng.range(1, 300));
+        let mut set = IntSet::new(min as isize, max as isize);
+        let mut model = BTreeSet::new();
+        for _ in 0..50 {
+            let item = rng.range(min, max) as isize;
+            if rng.next_u64().is_multiple_of(3) {
+                set.remove(item);
+                model.remove(&item);
+            } else {
+                set.add(item);
+                model.insert(item);
+            }
+        }
+        assert_eq!(set.count(), model.len());
+        assert!((min..max).all(|item| set.contains(item as isize) == model.contains(&(item as isize))));
+    }
+}
+
+#[test]
+fn algebra_matches_btreeset() {
+    let mut rng = Rng::new(11);
+    for _ in 0..CASES {
+        let random_set = |rng: &mut Rng| -> (IntSet, BTreeSet<isize>) {
+            let mut set = IntSet::new(-70, 130);
+            let mut model = BTreeSet::new();
+            for _ in 0..rng.range(0, 60) {
+                let item = rng.range(-70, 130) as isize;
+                set.add(item);
+                model.insert(item);
+            }
+            (set, model)
+        };
+        let (a, model_a) = random_set(&mut rng);
+        let (b, model_b) = random_set(&mut rng);
+        assert!(a.iter().eq(model_a.iter().copied()));
+        assert_eq!(a.first(), model_a.first().copied());
+        assert_eq!(a.last(), model_a.last().copied());
+        let probe = rng.range(-80, 140) as isize;
+        assert_eq!(a.next_after(probe), model_a.range(probe + 1..).next().copied());
+
+        let mut union = a.clone();
+        union.union(&b).unwrap();
+        assert!(union.iter().eq(model_a.union(&model_b).copied()));
+        let mut difference = a.clone();
+        difference.difference(&b).unwrap();
+        assert!(difference.iter().eq(model_a.difference(&model_b).copied()));
+        let mut symmetric_difference = a.clone();
+        symmetric_difference.symmetric_difference(&b).unwrap();
+        assert!(symmetric_difference
+            .iter()
+            .eq(model_a.symmetric_difference(&model_b).copied()));
+        assert_eq!(a.is_subset(&b), model_a.is_subset(&model_b));
+        assert_eq!(a.is_disjoint(&b), model_a.is_disjoint(&model_b));
+    }
+}
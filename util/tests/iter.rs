@@ -0,0 +1,29 @@
+mod common;
+
+use aoc_util::{interleave::InterleaveIter, top::TopIter};
+use common::{Rng, CASES};
+
+#[test]
+fn top_elements() {
+    assert_eq!([5, 1, 9, 3, 7].into_iter().top(3), vec![5, 7, 9]);
+    assert_eq!([2, 2, 1].into_iter().top(2), vec![2, 2]);
+}
+
+#[test]
+fn top_matches_sort() {
+    let mut rng = Rng::new(6);
+    for _ in 0..CASES {
+        let values: Vec<i64> = (0..rng.range(5, 40)).map(|_| rng.range(-50, 50)).collect();
+        let num_elems = rng.range(1, 5) as usize;
+        let mut sorted = values.clone();
+        sorted.sort();
+        let sorted = sorted.split_off(sorted.len() - num_elems);
+        assert_eq!(values.into_iter().top(num_elems), sorted);
+    }
+}
+
+#[test]
+fn interleave_separators() {
+    let interleaved: String = "abcdefg".chars().interleave('-', 3).take(9).collect();
+    assert_eq!(interleaved, "abc-def-g");
+}
@@ -0,0 +1,197 @@
+mod common;
+
+use aoc_util::number::{checked_lcm_all, crt, lcm_all, CongruenceError, ExtendedGcd, Gcd, Modular, Primes};
+use common::{Rng, CASES};
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(12u32.gcd(18), 6);
+    assert_eq!(17u64.gcd(5), 1);
+    assert_eq!(0i32.gcd(9), 9);
+    assert_eq!(4usize.lcm(6), 12);
+    assert_eq!(7i64.lcm(3), 21);
+}
+
+#[test]
+fn gcd_divides_both() {
+    let mut rng = Rng::new(3);
+    for _ in 0..CASES {
+        let a = rng.range(1, 100_000);
+        let b = rng.range(1, 100_000);
+        let gcd = a.gcd(b);
+        assert_eq!(a % gcd, 0);
+        assert_eq!(b % gcd, 0);
+        assert_eq!((a / gcd).gcd(b / gcd), 1);
+    }
+}
+
+#[test]
+fn lcm_is_common_multiple() {
+    let mut rng = Rng::new(4);
+    for _ in 0..CASES {
+        let a = rng.range(1, 100_000);
+        let b = rng.range(1, 100_000);
+        let lcm = a.lcm(b);
+        assert_eq!(lcm % a, 0);
+        assert_eq!(lcm % b, 0);
+        assert_eq!(lcm * a.gcd(b), a * b);
+    }
+}
+
+#[test]
+fn checked_lcm_overflows() {
+    assert_eq!(12u8.checked_lcm(18), Some(36));
+    assert_eq!(200u8.checked_lcm(3), None);
+    assert_eq!(0u32.checked_lcm(7), Some(0));
+    assert_eq!(lcm_all([4u64, 6, 10]), 60);
+    assert_eq!(lcm_all(Vec::<i32>::new()), 1);
+    assert_eq!(checked_lcm_all([2i16, 3, 5, 7, 11, 13]), Some(30030));
+    assert_eq!(checked_lcm_all([2i16, 3, 5, 7, 11, 13, 17]), None);
+}
+
+#[test]
+fn extended_gcd_satisfies_bezout() {
+    assert_eq!(240i32.extended_gcd(46), (2, -9, 47));
+    let mut rng = Rng::new(5);
+    for _ in 0..CASES {
+        let a = rng.range(-100_000, 100_000);
+        let b = rng.range(-100_000, 100_000);
+        let (gcd, x, y) = a.extended_gcd(b);
+        assert_eq!(a * x + b * y, gcd);
+        assert_eq!(gcd.abs(), a.abs().gcd(b.abs()));
+    }
+}
+
+#[test]
+fn modular_arithmetic() {
+    assert_eq!(3u64.mod_pow(200, 13), 9);
+    assert_eq!(5u32.mod_pow(0, 1), 0);
+    assert_eq!(u64::MAX.mod_mul(u64::MAX, 1_000_000_007), 114_944_269);
+    assert_eq!(3u64.mod_inverse(11), Some(4));
+    assert_eq!(6u64.mod_inverse(9), None);
+
+    let mut rng = Rng::new(6);
+    for _ in 0..CASES {
+        let modulus = rng.range(1, 1000) as u64;
+        let base = rng.range(0, 1_000_000) as u64;
+        let exp = rng.range(0, 50) as u64;
+        let naive = (0..exp).fold(1 % modulus, |acc, _| acc * (base % modulus) % modulus);
+        assert_eq!(base.mod_pow(exp, modulus), naive);
+        match base.mod_inverse(modulus) {
+            Some(inverse) => assert_eq!(base.mod_mul(inverse, modulus), 1 % modulus),
+            None => assert_ne!(base.gcd(modulus), 1),
+        }
+    }
+}
+
+#[test]
+fn crt_matches_brute_force() {
+    assert_eq!(crt([(2u64, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+    assert_eq!(crt([(1u32, 4), (3, 6)]), Ok((9, 12)));
+    assert_eq!(crt([(1u32, 4), (2, 6)]), Err(CongruenceError::NoSolution));
+    assert_eq!(crt(Vec::<(u8, u8)>::new()), Ok((0, 1)));
+    assert_eq!(crt([(1u8, 16), (0, 17)]), Err(CongruenceError::Overflow));
+    assert_eq!(crt([(1u8, 16), (2, 16), (0, 17)]), Err(CongruenceError::NoSolution));
+
+    let mut rng = Rng::new(7);
+    for _ in 0..CASES {
+        let congruences: Vec<(u64, u64)> = (0..rng.range(1, 4))
+            .map(|_| {
+                let modulus = rng.range(1, 30) as u64;
+                (rng.range(0, 100) as u64, modulus)
+            })
+            .collect();
+        let lcm = lcm_all(congruences.iter().map(|&(_, modulus)| modulus));
+        let expected = (0..lcm).find(|x| congruences.iter().all(|&(r, m)| x % m == r % m));
+        assert_eq!(
+            crt(congruences.iter().copied()),
+            expected.map(|x| (x, lcm)).ok_or(CongruenceError::NoSolution)
+        );
+    }
+}
+
+#[test]
+fn congruences_with_huge_moduli() {
+    // The LCM of these primes takes 128 bits.
+    let (p, q) = (18_446_744_073_709_551_557, 18_446_744_073_709_551_533);
+    assert_eq!(
+        u64::combine_congruences((u64::MAX - 100, p), (1, q)),
+        Err(CongruenceError::Overflow)
+    );
+    assert_eq!(crt([(3, p), (4, q)]), Err(CongruenceError::Overflow));
+    // Both moduli are even, so an even and an odd remainder have no solution, however big the LCM is.
+    assert_eq!(
+        u64::combine_congruences((0, u64::MAX - 1), (1, u64::MAX - 3)),
+        Err(CongruenceError::NoSolution)
+    );
+    assert_eq!(u64::combine_congruences((5, u64::MAX), (5, u64::MAX)), Ok((5, u64::MAX)));
+    assert_eq!(
+        u64::combine_congruences((u64::MAX - 1, u64::MAX), (1, 1)),
+        Ok((u64::MAX - 1, u64::MAX))
+    );
+
+    let mut rng = Rng::new(10);
+    for _ in 0..CASES {
+        // Moduli of about 32 bits make some LCMs fit in 64 bits and others not.
+        let [m1, m2] = [0; 2].map(|_| (rng.next_u64() >> 31).max(1));
+        let [r1, r2] = [rng.next_u64(), rng.next_u64()];
+        let gcd = m1.gcd(m2);
+        let lcm = m1 as u128 / gcd as u128 * m2 as u128;
+        match u64::combine_congruences((r1, m1), (r2, m2)) {
+            Ok((x, combined)) => {
+                assert_eq!(combined as u128, lcm);
+                assert!(x < combined && x % m1 == r1 % m1 && x % m2 == r2 % m2);
+            }
+            Err(CongruenceError::NoSolution) => assert_ne!((r1 % m1).abs_diff(r2 % m2) % gcd, 0),
+            Err(CongruenceError::Overflow) => {
+                assert!(lcm > u64::MAX as u128);
+                assert_eq!((r1 % m1).abs_diff(r2 % m2) % gcd, 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn is_prime_matches_sieve() {
+    let mut sieve = vec![true; 10_000];
+    sieve[0] = false;
+    sieve[1] = false;
+    for n in 2..sieve.len() {
+        if sieve[n] {
+            (n * n..sieve.len()).step_by(n).for_each(|multiple| sieve[multiple] = false);
+        }
+    }
+    for (n, &prime) in sieve.iter().enumerate() {
+        assert_eq!((n as u64).is_prime(), prime, "{n}");
+    }
+
+    assert!(1_000_000_007u64.is_prime());
+    assert!(18_446_744_073_709_551_557u64.is_prime());
+    // Carmichael numbers and strong pseudoprimes to several bases.
+    assert!(!561u32.is_prime());
+    assert!(!3_215_031_751u64.is_prime());
+    assert!(!3_825_123_056_546_413_051u64.is_prime());
+    assert!(!u64::MAX.is_prime());
+}
+
+#[test]
+fn prime_factors_multiply_back() {
+    assert_eq!(0u32.prime_factors(), vec![]);
+    assert_eq!(1u32.prime_factors(), vec![]);
+    assert_eq!(360u16.prime_factors(), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(
+        (1_000_000_007u64 * 998_244_353).prime_factors(),
+        vec![(998_244_353, 1), (1_000_000_007, 1)]
+    );
+
+    let mut rng = Rng::new(9);
+    for _ in 0..CASES {
+        let n = rng.next_u64() >> rng.range(0, 64);
+        let factors = n.prime_factors();
+        assert!(factors.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(factors.iter().all(|&(factor, _)| factor.is_prime()));
+        if n > 0 {
+            assert_eq!(factors.iter().map(|&(factor, exp)| factor.pow(exp)).product::<u64>(), n);
+        }
+    }
+}
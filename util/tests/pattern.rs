@@ -0,0 +1,92 @@
+mod common;
+
+use aoc_util::pattern::{Pattern, PatternError};
+use common::{Rng, CASES};
+
+/// Pieces of random patterns, along with the characters each one matches.
+const ATOMS: [(&str, &[char]); 5] = [
+    ("a", &['a']),
+    ("b", &['b']),
+    (".", &['a', 'b', 'c']),
+    ("[ab]", &['a', 'b']),
+    ("[^a]", &['b', 'c']),
+];
+const QUANTIFIERS: [&str; 4] = ["", "*", "+", "?"];
+
+/// Returns whether the pieces match the whole of `text`, by trying every way of splitting it between them.
+fn brute_force_match(pieces: &[(&[char], &str)], text: &[char]) -> bool {
+    let Some(((matches, quantifier), rest)) = pieces.split_first() else {
+        return text.is_empty();
+    };
+    let (min, max) = match *quantifier {
+        "" => (1, 1),
+        "*" => (0, usize::MAX),
+        "+" => (1, usize::MAX),
+        _ => (0, 1),
+    };
+    let mut count = 0;
+    loop {
+        if count >= min && brute_force_match(rest, &text[count..]) {
+            return true;
+        }
+        if count == max || count == text.len() || !matches.contains(&text[count]) {
+            return false;
+        }
+        count += 1;
+    }
+}
+
+#[test]
+fn matches_whole_strings() {
+    let pattern = Pattern::new("..Z").unwrap();
+    assert!(pattern.is_match("AAZ"));
+    assert!(!pattern.is_match("AZ"));
+    assert!(!pattern.is_match("AAZZ"));
+
+    let pattern = Pattern::new("[A-C]+1?[^0-9]*").unwrap();
+    assert!(pattern.is_match("CAB"));
+    assert!(pattern.is_match("A1XYZ"));
+    assert!(!pattern.is_match("1XYZ"));
+    assert!(!pattern.is_match("A12"));
+
+    assert!(Pattern::new("").unwrap().is_match(""));
+    assert!(Pattern::new(r"a\*\[").unwrap().is_match("a*["));
+    assert!(Pattern::new("[]a-]*").unwrap().is_match("]-a"));
+    assert!(!Pattern::new("[^]]").unwrap().is_match("]"));
+}
+
+#[test]
+fn invalid_patterns() {
+    assert_eq!(Pattern::new("*a"), Err(PatternError::MissingAtom('*')));
+    assert_eq!(Pattern::new("a+?"), Err(PatternError::MissingAtom('?')));
+    assert_eq!(Pattern::new("[ab"), Err(PatternError::UnclosedClass));
+    assert_eq!(Pattern::new("[]"), Err(PatternError::UnclosedClass));
+    assert_eq!(Pattern::new(r"a\"), Err(PatternError::TrailingEscape));
+}
+
+#[test]
+fn random_patterns_match_brute_force() {
+    let mut rng = Rng::new(41);
+    for _ in 0..CASES {
+        let pieces: Vec<(&str, &[char], &str)> = (0..rng.range(0, 6))
+            .map(|_| {
+                let (atom, matches) = ATOMS[rng.range(0, ATOMS.len() as i64) as usize];
+                (atom, matches, QUANTIFIERS[rng.range(0, QUANTIFIERS.len() as i64) as usize])
+            })
+            .collect();
+        let source: String = pieces.iter().flat_map(|(atom, _, quantifier)| [*atom, *quantifier]).collect();
+        let pattern = Pattern::new(&source).unwrap();
+        let pieces: Vec<(&[char], &str)> = pieces.iter().map(|&(_, matches, quantifier)| (matches, quantifier)).collect();
+        for _ in 0..20 {
+            let text: Vec<char> = (0..rng.range(0, 8))
+                .map(|_| ['a', 'b', 'c'][rng.range(0, 3) as usize])
+                .collect();
+            let expected = brute_force_match(&pieces, &text);
+            assert_eq!(
+                pattern.is_match(&text.iter().collect::<String>()),
+                expected,
+                "{source} on {text:?}"
+            );
+        }
+    }
+}
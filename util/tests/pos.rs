@@ -0,0 +1,235 @@
+mod common;
+
+use aoc_util::pos::{Cuboid, Dir4, Dir8, Pos2d, Pos3d, PosN};
+use common::{Rng, CASES};
+
+#[test]
+fn pos2d_arithmetic() {
+    let a = Pos2d::new(3, -4);
+    let b = Pos2d::new(-1, 2);
+    assert_eq!(a + b, Pos2d::new(2, -2));
+    assert_eq!(a - b, Pos2d::new(4, -6));
+    assert_eq!(a * 2, Pos2d::new(6, -8));
+    assert_eq!(a / 2, Pos2d::new(1, -2));
+    assert_eq!(a.add(1, 1), Pos2d::new(4, -3));
+}
+
+#[test]
+fn pos2d_abs_max_sum() {
+    let pos = Pos2d::new(-7i32, 5);
+    assert_eq!(pos.abs(), Pos2d::new(7, 5));
+    assert_eq!(pos.abs().max(), 7);
+    assert_eq!(pos.sum::<i64>(), -2);
+}
+
+#[test]
+fn pos_display() {
+    assert_eq!(Pos2d::new(1, -2).to_string(), "(1, -2)");
+    assert_eq!(Pos3d::new(1, 2, 3).to_string(), "(1, 2, 3)");
+}
+
+#[test]
+fn pos3d_arithmetic() {
+    let a = Pos3d::new(1, 2, 3);
+    let b = Pos3d::new(-1, 0, 4);
+    assert_eq!(a + b, Pos3d::new(0, 2, 7));
+    assert_eq!(a - b, Pos3d::new(2, 2, -1));
+    assert_eq!(a * 3, Pos3d::new(3, 6, 9));
+    assert_eq!(a / 2, Pos3d::new(0, 1, 1));
+    assert_eq!(a.add(1, 1, 1), Pos3d::new(2, 3, 4));
+    assert_eq!(Pos3d::new(-4, 2, -9).abs(), Pos3d::new(4, 2, 9));
+    assert_eq!(Pos3d::new(-4, 2, -9).abs().max(), 9);
+    assert_eq!(Pos3d::new(-4, 2, -9).sum::<i64>(), -11);
+}
+
+#[test]
+fn pos3d_products() {
+    let x = Pos3d::new(1, 0, 0);
+    let y = Pos3d::new(0, 1, 0);
+    assert_eq!(x.cross(y), Pos3d::new(0, 0, 1));
+    assert_eq!(y.cross(x), Pos3d::new(0, 0, -1));
+    assert_eq!(Pos3d::new(1, 2, 3).dot(Pos3d::new(4, -5, 6)), 12);
+}
+
+#[test]
+fn pos3d_min_max_and_bounding_box() {
+    let a = Pos3d::new(1, 5, -2);
+    let b = Pos3d::new(3, 0, -7);
+    assert_eq!(a.component_min(b), Pos3d::new(1, 0, -7));
+    assert_eq!(a.component_max(b), Pos3d::new(3, 5, -2));
+    assert_eq!(a.manhattan_distance(b), 12);
+    assert_eq!(
+        Pos3d::bounding_box([a, b, Pos3d::new(0, 9, 0)]),
+        Some(Cuboid::new(Pos3d::new(0, 0, -7), Pos3d::new(3, 9, 0)))
+    );
+    assert_eq!(Pos3d::<i32>::bounding_box([]), None);
+}
+
+#[test]
+fn pos3d_neighbors() {
+    let pos = Pos3d::new(1u8, 1, 1);
+    assert_eq!(pos.neighbors6().count(), 6);
+    assert!(pos.neighbors6().all(|neighbor| neighbor.manhattan_distance(pos) == 1));
+    let neighbors: Vec<Pos3d<u8>> = pos.neighbors26().collect();
+    assert_eq!(neighbors.len(), 26);
+    assert_eq!(neighbors[0], Pos3d::new(0, 0, 0));
+    assert_eq!(neighbors[25], Pos3d::new(2, 2, 2));
+    assert!(!neighbors.contains(&pos));
+}
+
+#[test]
+fn pos3d_cross_is_orthogonal() {
+    let mut rng = Rng::new(8);
+    for _ in 0..CASES {
+        let a = Pos3d::new(rng.range(-100, 100), rng.range(-100, 100), rng.range(-100, 100));
+        let b = Pos3d::new(rng.range(-100, 100), rng.range(-100, 100), rng.range(-100, 100));
+        let cross = a.cross(b);
+        assert_eq!(cross.dot(a), 0);
+        assert_eq!(cross.dot(b), 0);
+        assert_eq!(b.cross(a), cross * -1);
+    }
+}
+
+#[test]
+fn pos2d_add_sub_roundtrip() {
+    let mut rng = Rng::new(1);
+    for _ in 0..CASES {
+        let a = Pos2d::new(rng.range(-1000, 1000), rng.range(-1000, 1000));
+        let b = Pos2d::new(rng.range(-1000, 1000), rng.range(-1000, 1000));
+        assert_eq!(a + b - b, a);
+        assert_eq!(a + b, b + a);
+    }
+}
+
+#[test]
+fn pos2d_abs_is_non_negative() {
+    let mut rng = Rng::new(2);
+    for _ in 0..CASES {
+        let pos = Pos2d::new(rng.range(-1000, 1000), rng.range(-1000, 1000));
+        let abs = pos.abs();
+        assert!(abs.x >= 0 && abs.y >= 0);
+        assert_eq!(abs, (pos * -1).abs());
+    }
+}
+
+#[test]
+fn dir_turning() {
+    assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+    assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+    assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+    assert_eq!(Dir8::Up.turn_right(), Dir8::UpRight);
+    assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+    assert_eq!(Dir8::DownLeft.reverse(), Dir8::UpRight);
+    for dir in Dir4::ALL {
+        assert_eq!(dir.turn_right().turn_left(), dir);
+        assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+        assert_eq!(Dir8::from(dir.reverse()), Dir8::from(dir).reverse());
+    }
+}
+
+#[test]
+fn step_and_neighbors() {
+    let pos = Pos2d::new(5u32, 5);
+    assert_eq!(pos.step(Dir4::Up), Pos2d::new(5, 4));
+    assert_eq!(pos.step(Dir8::DownLeft), Pos2d::new(4, 6));
+    let neighbors: Vec<Pos2d<u32>> = pos.neighbors4().collect();
+    assert_eq!(
+        neighbors,
+        [Pos2d::new(5, 4), Pos2d::new(6, 5), Pos2d::new(5, 6), Pos2d::new(4, 5)]
+    );
+    assert_eq!(pos.neighbors8().count(), 8);
+    assert!(pos.neighbors8().all(|neighbor| neighbor.chebyshev_distance(pos) == 1));
+}
+
+#[test]
+fn distances() {
+    let a = Pos2d::new(1u8, 7);
+    let b = Pos2d::new(4u8, 2);
+    assert_eq!(a.manhattan_distance(b), 8);
+    assert_eq!(a.chebyshev_distance(b), 5);
+    assert_eq!(Pos2d::new(-3, 0).manhattan_distance(Pos2d::new(3, 0)), 6);
+}
+
+#[test]
+fn rotation() {
+    let center = Pos2d::new(2, 2);
+    assert_eq!(Pos2d::new(2, 0).rotate_right(center), Pos2d::new(4, 2));
+    assert_eq!(Pos2d::new(2, 0).rotate_left(center), Pos2d::new(0, 2));
+    assert_eq!(Pos2d::new(3usize, 1).rotate_right(center), Pos2d::new(3, 3));
+}
+
+#[test]
+fn rotation_properties() {
+    let mut rng = Rng::new(7);
+    for _ in 0..CASES {
+        let pos = Pos2d::new(rng.range(-1000, 1000), rng.range(-1000, 1000));
+        let center = Pos2d::new(rng.range(-1000, 1000), rng.range(-1000, 1000));
+        let once = pos.rotate_right(center);
+        assert_eq!(once.rotate_left(center), pos);
+        assert_eq!(once.rotate_right(center).rotate_right(center).rotate_right(center), pos);
+        assert_eq!(once.manhattan_distance(center), pos.manhattan_distance(center));
+    }
+}
+
+#[test]
+fn posn_arithmetic_and_norms() {
+    let a = PosN::new([1, -2, 3, -4]);
+    let b = PosN::new([0, 2, 0, 2]);
+    assert_eq!(a + b, PosN::new([1, 0, 3, -2]));
+    assert_eq!(a - b, PosN::new([1, -4, 3, -6]));
+    assert_eq!(a * 2, PosN::new([2, -4, 6, -8]));
+    assert_eq!(a / 2, PosN::new([0, -1, 1, -2]));
+    assert_eq!(a.abs(), PosN::new([1, 2, 3, 4]));
+    assert_eq!(a.dot(b), -12);
+    assert_eq!(a.manhattan_norm(), 10);
+    assert_eq!(a.chebyshev_norm(), 4);
+    assert_eq!(a.manhattan_distance(b), 14);
+    assert_eq!(a.chebyshev_distance(b), 6);
+    assert_eq!(a[3], -4);
+    assert_eq!(a.to_string(), "(1, -2, 3, -4)");
+    assert_eq!(PosN::<u8, 2>::default(), PosN::new([0, 0]));
+}
+
+#[test]
+fn posn_neighbors() {
+    let pos = PosN::new([1u8; 4]);
+    assert_eq!(pos.orthogonal_neighbors().count(), 8);
+    assert!(pos
+        .orthogonal_neighbors()
+        .all(|neighbor| neighbor.manhattan_distance(pos) == 1));
+    assert_eq!(pos.neighbors().count(), 80);
+    assert!(pos.neighbors().all(|neighbor| neighbor.chebyshev_distance(pos) == 1));
+}
+
+#[test]
+fn posn_matches_pos2d_and_pos3d() {
+    let pos2 = Pos2d::new(3, 4);
+    let pos3 = Pos3d::new(3, 4, 5);
+    assert_eq!(Pos2d::from(PosN::from(pos2)), pos2);
+    assert_eq!(Pos3d::from(PosN::from(pos3)), pos3);
+
+    let neighbors2: Vec<Pos2d<i32>> = PosN::from(pos2).neighbors().map(Pos2d::from).collect();
+    let mut expected2: Vec<Pos2d<i32>> = pos2.neighbors8().collect();
+    expected2.sort_by_key(|pos| (pos.y, pos.x));
+    assert_eq!(neighbors2, expected2);
+
+    let neighbors3: Vec<Pos3d<i32>> = PosN::from(pos3).neighbors().map(Pos3d::from).collect();
+    assert_eq!(neighbors3, pos3.neighbors26().collect::<Vec<_>>());
+    let mut orthogonal3: Vec<Pos3d<i32>> = PosN::from(pos3).orthogonal_neighbors().map(Pos3d::from).collect();
+    let mut expected3: Vec<Pos3d<i32>> = pos3.neighbors6().collect();
+    orthogonal3.sort_by_key(|pos| (pos.x, pos.y, pos.z));
+    expected3.sort_by_key(|pos| (pos.x, pos.y, pos.z));
+    assert_eq!(orthogonal3, expected3);
+}
+
+#[test]
+fn posn_distance_properties() {
+    let mut rng = Rng::new(9);
+    for _ in 0..CASES {
+        let a = PosN::new([(); 5].map(|_| rng.range(-100, 100)));
+        let b = PosN::new([(); 5].map(|_| rng.range(-100, 100)));
+        assert_eq!(a.manhattan_distance(b), (a - b).manhattan_norm());
+        assert!(a.chebyshev_distance(b) <= a.manhattan_distance(b));
+        assert_eq!(a.manhattan_distance(b), b.manhattan_distance(a));
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::{BTreeSet, HashSet};
+
+use aoc_util::{
+    bitset::BitSet,
+    intset::IntSet,
+    set::{IncompatibleSetsError, Set},
+};
+
+/// Exercises a set through the [`Set`] trait only, so every implementation is held to the same behaviour.
+fn check_set<S: Set<usize>>(mut a: S, mut b: S) {
+    assert!(a.is_empty());
+    [1, 5, 9, 40].into_iter().for_each(|item| a.add(item));
+    [5, 40, 41].into_iter().for_each(|item| b.add(item));
+    a.add(9);
+    assert_eq!(a.len(), 4);
+    assert!(a.contains(9) && !a.contains(2));
+    a.remove(9);
+    a.remove(2);
+    assert_eq!(a.len(), 3);
+
+    a.union_with(&b).unwrap();
+    let mut items: Vec<usize> = a.items().collect();
+    items.sort();
+    assert_eq!(items, [1, 5, 40, 41]);
+
+    a.difference_with(&b).unwrap();
+    assert_eq!(a.items().collect::<Vec<usize>>(), [1]);
+
+    a.add(41);
+    a.intersect(&b).unwrap();
+    assert_eq!(a.items().collect::<Vec<usize>>(), [41]);
+
+    a.clear();
+    assert!(a.is_empty());
+}
+
+/// Adapts an [`IntSet`] to `usize` items, so it can be checked alongside the other sets.
+struct UsizeIntSet(IntSet);
+
+impl Set<usize> for UsizeIntSet {
+    fn add(&mut self, item: usize) {
+        self.0.add(item as isize);
+    }
+
+    fn remove(&mut self, item: usize) {
+        self.0.remove(item as isize);
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn contains(&self, item: usize) -> bool {
+        self.0.contains(item as isize)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn items(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.items().map(|item| item as usize)
+    }
+
+    fn intersect(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.0.intersect(&other.0)
+    }
+
+    fn union_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.0.union(&other.0)
+    }
+
+    fn difference_with(&mut self, other: &Self) -> Result<(), IncompatibleSetsError> {
+        self.0.difference(&other.0)
+    }
+}
+
+#[test]
+fn std_sets() {
+    check_set(HashSet::new(), HashSet::new());
+    check_set(BTreeSet::new(), BTreeSet::new());
+}
+
+#[test]
+fn bit_sets() {
+    check_set(BitSet::<1>::new(), BitSet::<1>::new());
+    check_set(UsizeIntSet(IntSet::new(0, 50)), UsizeIntSet(IntSet::new(0, 50)));
+}
+
+#[test]
+fn trait_methods_on_std_sets() {
+    // The inherent `union` and `difference` of std's sets return iterators, so the trait's methods need other names.
+    let mut a = HashSet::from([1, 2]);
+    let b = HashSet::from([2, 3]);
+    assert_eq!(a.union(&b).count(), 3);
+    a.union_with(&b).unwrap();
+    assert_eq!(a, HashSet::from([1, 2, 3]));
+    a.difference_with(&b).unwrap();
+    assert_eq!(a, HashSet::from([1]));
+}